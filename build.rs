@@ -0,0 +1,34 @@
+#[path = "build/words.rs"]
+mod words;
+
+fn main() {
+    words::generate(std::path::Path::new("words.txt"));
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+// The header goes to OUT_DIR, where a test compares it with the one kept in the repository
+// for the native apps. `TGG_REGENERATE_HEADER=1` overwrites the kept one after the C API changes.
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/lib/ffi.rs");
+    println!("cargo:rerun-if-env-changed=TGG_REGENERATE_HEADER");
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let mut config = cbindgen::Config::default();
+    config.language = cbindgen::Language::C;
+    config.include_guard = Some(String::from("TINKOFF_GUESS_GAME_H"));
+    config.autogen_warning = Some(String::from("/* Generated by build.rs from src/lib/ffi.rs, do not edit. */"));
+    config.cpp_compat = true;
+    config.usize_is_size_t = true;
+    config.enumeration.prefix_with_name = true;
+    let header = cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(format!("{}/src/lib/ffi.rs", crate_dir))
+        .generate()
+        .expect("C header is not generated");
+    header.write_to_file(format!("{}/tinkoff_guess_game.h", out_dir));
+    if std::env::var("TGG_REGENERATE_HEADER").is_ok_and(|value| value == "1") {
+        header.write_to_file(format!("{}/include/tinkoff_guess_game.h", crate_dir));
+    }
+}
@@ -0,0 +1,48 @@
+// Generates the built-in dictionary of `src/lib/words.rs` from the word list,
+// shared by the build scripts of the helper and of the app.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+const WORD_LENGTH: usize = 5;
+
+/// Checks the list the way `dict validate` does and writes `$OUT_DIR/words.rs`
+/// with the sorted words and their letters.
+pub fn generate(source: &Path) {
+    println!("cargo:rerun-if-changed={}", source.display());
+    let text = fs::read_to_string(source).unwrap_or_else(|err| panic!("Can't read {}: {}", source.display(), err));
+
+    let mut words = Vec::new();
+    let mut seen = HashSet::new();
+    for (idx, line) in text.lines().enumerate() {
+        let word = line.trim();
+        if word.is_empty() || word.starts_with('#') {
+            continue;
+        }
+        let is_word = word.chars().count() == WORD_LENGTH && word.chars().all(|ch| ('а'..='я').contains(&ch));
+        if !is_word || !seen.insert(word) {
+            panic!("{}:{}: `{}` is wrong or repeated, run `dict validate` for the details", source.display(), idx + 1, word);
+        }
+        words.push(word);
+    }
+    words.sort();
+
+    let mut module = String::new();
+    writeln!(module, "pub const WORD_COUNT: usize = {};", words.len()).unwrap();
+    writeln!(module, "pub const WORDLIST: &[&str; WORD_COUNT] = &[").unwrap();
+    for word in words.iter() {
+        writeln!(module, "    {:?},", word).unwrap();
+    }
+    writeln!(module, "];").unwrap();
+    writeln!(module, "pub const LETTERS: &[[char; {}]; WORD_COUNT] = &[", WORD_LENGTH).unwrap();
+    for word in words.iter() {
+        let letters: Vec<String> = word.chars().map(|ch| format!("{:?}", ch)).collect();
+        writeln!(module, "    [{}],", letters.join(", ")).unwrap();
+    }
+    writeln!(module, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("words.rs"), module).expect("The dictionary is not written");
+}
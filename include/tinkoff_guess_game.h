@@ -0,0 +1,106 @@
+#ifndef TINKOFF_GUESS_GAME_H
+#define TINKOFF_GUESS_GAME_H
+
+/* Generated by build.rs from src/lib/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum TggStatus {
+  TggStatus_Ok = 0,
+  /**
+   * The row doesn't make a word of five letters.
+   */
+  TggStatus_WrongRow = 1,
+  /**
+   * The row contradicts the previous ones.
+   */
+  TggStatus_ConflictingRows = 2,
+  /**
+   * A null pointer or not UTF-8 string is given.
+   */
+  TggStatus_InvalidArgument = 3,
+} TggStatus;
+
+/**
+ * The entered rows and the words which may still be the answer.
+ */
+typedef struct TggSession TggSession;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates an empty session, free it with `tgg_session_free`.
+ */
+struct TggSession *tgg_session_new(void);
+
+/**
+ * # Safety
+ *
+ * The session must come from `tgg_session_new` and not be used afterwards. Null is ignored.
+ */
+void tgg_session_free(struct TggSession *session);
+
+/**
+ * Adds the row in the `=`/`?` notation, e.g. `с=удь=я`. The row is kept only if it agrees
+ * with the previous ones, otherwise `tgg_session_last_error` tells what is wrong.
+ *
+ * # Safety
+ *
+ * The session must be alive, the row must be a NUL-terminated string.
+ */
+enum TggStatus tgg_session_add_row(struct TggSession *session, const char *row);
+
+/**
+ * Message of the last failed `tgg_session_add_row`, null if it succeeded.
+ * The string belongs to the session and lives until the next call.
+ *
+ * # Safety
+ *
+ * The session must be alive.
+ */
+const char *tgg_session_last_error(const struct TggSession *session);
+
+/**
+ * # Safety
+ *
+ * The session must be alive.
+ */
+size_t tgg_session_candidate_count(const struct TggSession *session);
+
+/**
+ * The words which may still be the answer, a line per word. Free with `tgg_string_free`.
+ *
+ * # Safety
+ *
+ * The session must be alive.
+ */
+char *tgg_session_candidates(const struct TggSession *session);
+
+/**
+ * The best next guesses as `word<TAB>bits` lines, best first. Free with `tgg_string_free`.
+ * Ranking for an empty session goes over the whole dictionary and takes a while.
+ *
+ * # Safety
+ *
+ * The session must be alive.
+ */
+char *tgg_session_rank(const struct TggSession *session, size_t count);
+
+/**
+ * # Safety
+ *
+ * The string must come from this library and not be used afterwards. Null is ignored.
+ */
+void tgg_string_free(char *string);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* TINKOFF_GUESS_GAME_H */
@@ -1,3 +1,7 @@
+#[path = "../build/words.rs"]
+mod words;
+
 fn main() {
+  words::generate(std::path::Path::new("../words.txt"));
   tauri_build::build()
 }
@@ -0,0 +1,173 @@
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::State;
+use tinkoff_guess_game_lib::{letters_to_string, parse_rows, Error};
+use tinkoff_guess_game_lib::explain::Explanation;
+use tinkoff_guess_game_lib::game::{is_known_word, row_word, Game, GuessError, Host};
+use tinkoff_guess_game_lib::keyboard::KeyboardState;
+use tinkoff_guess_game_lib::rank::DEFAULT_RANK_COUNT;
+use tinkoff_guess_game_lib::solve::{ranking, Candidate};
+
+#[derive(Debug, Serialize)]
+pub struct SolveResponse {
+  count: usize,
+  candidates: Vec<Candidate>,
+  /// Things which are not errors, but likely typos.
+  warnings: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GameView {
+  rows: Vec<String>,
+  attempts: usize,
+  max_attempts: Option<usize>,
+  won: bool,
+  over: bool,
+  /// Shown only when the game is over.
+  answer: Option<String>,
+}
+
+/// The game played in the desktop app.
+#[derive(Default)]
+pub struct CurrentGame(Mutex<Option<Game>>);
+
+fn game_view(game: &Game) -> GameView {
+  GameView {
+    rows: game.rows().iter().map(|row| letters_to_string(row)).collect(),
+    attempts: game.attempts(),
+    max_attempts: game.max_attempts(),
+    won: game.is_won(),
+    over: game.is_over(),
+    answer: game.answer().filter(|_| game.is_over()).map(String::from),
+  }
+}
+
+#[tauri::command]
+pub fn solve(rows: Vec<String>) -> Result<SolveResponse, Error> {
+  let solution = tinkoff_guess_game_lib::solve::solve(&rows)?;
+  let mut warnings: Vec<String> = parse_rows(&rows)?.iter()
+    .map(|row| row_word(row))
+    .filter(|word| !is_known_word(word))
+    .map(|word| format!("Word `{}` is not in the dictionary", word))
+    .collect();
+  if solution.remaining == 0 {
+    warnings.push(String::from("No words match the rows, check the colors"));
+  }
+  Ok(SolveResponse { count: solution.remaining, candidates: solution.candidates, warnings })
+}
+
+#[tauri::command]
+pub fn rank_guesses(rows: Vec<String>, count: Option<usize>) -> Result<Vec<Candidate>, Error> {
+  ranking(&rows, count.unwrap_or(DEFAULT_RANK_COUNT))
+}
+
+#[tauri::command]
+pub fn explain_word(word: String, rows: Vec<String>) -> Result<Explanation, Error> {
+  tinkoff_guess_game_lib::explain::explain_word(&word, &rows)
+}
+
+#[tauri::command]
+pub fn keyboard_state(rows: Vec<String>) -> Result<KeyboardState, Error> {
+  tinkoff_guess_game_lib::keyboard::keyboard_state(&rows)
+}
+
+/// Rows of the game pasted from the clipboard.
+#[tauri::command]
+pub fn import_game(text: String) -> Result<Vec<String>, Error> {
+  tinkoff_guess_game_lib::import::import_game(&text)
+}
+
+#[tauri::command]
+pub fn new_game(current: State<'_, CurrentGame>, seed: Option<u64>) -> Result<GameView, Error> {
+  Ok(start_game(&current, seed))
+}
+
+#[tauri::command]
+pub fn play_guess(current: State<'_, CurrentGame>, word: String) -> Result<GameView, Error> {
+  guess_in_game(&current, &word)
+}
+
+fn start_game(current: &CurrentGame, seed: Option<u64>) -> GameView {
+  let game = match seed {
+    Some(seed) => Game::with_seed(seed),
+    None => Game::random(),
+  };
+  let view = game_view(&game);
+  *current.0.lock().unwrap() = Some(game);
+  view
+}
+
+fn guess_in_game(current: &CurrentGame, word: &str) -> Result<GameView, Error> {
+  let mut current = current.0.lock().unwrap();
+  let game = current.as_mut().ok_or(GuessError::GameOver)?;
+  game.guess(word)?;
+  Ok(game_view(game))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_solve() {
+    let response = solve(["лента", "с=удь=я"].map(String::from).to_vec()).unwrap();
+    assert_eq!(response.count, 5);
+    assert_eq!(response.candidates.len(), 5);
+    assert!(response.warnings.is_empty());
+  }
+
+  #[test]
+  fn test_solve_warnings() {
+    let response = solve(["ыыыыы"].map(String::from).to_vec()).unwrap();
+    assert_eq!(response.warnings[0], "Word `ыыыыы` is not in the dictionary");
+    let response = solve(["=ъ=ъ=ъ=ъ=ъ"].map(String::from).to_vec()).unwrap();
+    assert_eq!(response.count, 0);
+    assert_eq!(response.warnings.last().unwrap(), "No words match the rows, check the colors");
+  }
+
+  #[test]
+  fn test_solve_error() {
+    let err = solve(["лент"].map(String::from).to_vec()).unwrap_err();
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(value["code"], "wrong_row");
+  }
+
+  #[test]
+  fn test_rank_guesses() {
+    let ranking = rank_guesses(["лента", "с=удь=я"].map(String::from).to_vec(), Some(3)).unwrap();
+    assert_eq!(ranking.len(), 3);
+    assert!(ranking[0].score >= ranking[2].score);
+  }
+
+  #[test]
+  fn test_explain_word() {
+    let explanation = explain_word(String::from("мумия"), ["лента", "с=удь=я"].map(String::from).to_vec()).unwrap();
+    assert!(explanation.candidate);
+  }
+
+  #[test]
+  fn test_keyboard_state() {
+    let state = keyboard_state(["с=удь=я"].map(String::from).to_vec()).unwrap();
+    let value = serde_json::to_value(&state).unwrap();
+    assert_eq!(value["letters"]["у"], "correct");
+  }
+
+  #[test]
+  fn test_import_game() {
+    let rows = import_game(String::from("лента судья\n⬛⬛⬛⬛⬛\n⬛🟨⬛⬛🟨")).unwrap();
+    assert_eq!(rows, vec!["лента", "с=удь=я"]);
+    assert_eq!(import_game(String::new()).unwrap_err().code(), "wrong_import");
+  }
+
+  #[test]
+  fn test_game() {
+    let current = CurrentGame::default();
+    assert_eq!(guess_in_game(&current, "лента").unwrap_err().code(), "game_over");
+    let view = start_game(&current, Some(1));
+    assert_eq!((view.attempts, view.answer), (0, None));
+    let view = guess_in_game(&current, "лента").unwrap();
+    assert_eq!(view.rows.len(), 1);
+    assert_eq!(guess_in_game(&current, "лент").unwrap_err().code(), "wrong_length");
+  }
+}
@@ -1,16 +1,94 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tinkoff_guess_game_lib::{guess_word, strings_to_words};
+use chrono::{Local, NaiveDate};
+use serde::Serialize;
+use tinkoff_guess_game_lib::{parse_rows, suggest_words, Error};
+use tinkoff_guess_game_lib::daily::word_of_the_day;
+use tinkoff_guess_game_lib::multiboard::MultiBoard;
+use tinkoff_guess_game_lib::session::{Mode, Session, SessionStore};
+use tinkoff_guess_game_lib::solve::{candidates, Candidate};
+
+mod commands;
+
+const DESKTOP_SESSION: &str = "desktop";
+
+#[derive(Serialize)]
+struct BoardSuggestions {
+  candidates: Vec<Vec<&'static str>>,
+  solved: Vec<Option<String>>,
+  recommended: Vec<Candidate>,
+}
+
+#[tauri::command]
+fn get_suggestions(words: Vec<String>) -> Result<Vec<&'static str>, Error> {
+  // Only the rows which make sense are saved.
+  let suggestions = if words.is_empty() { suggest_words() } else { candidates(&words)? };
+  save_desktop_session(&words);
+  Ok(suggestions)
+}
+
+#[tauri::command]
+fn save_session(rows: Vec<String>) {
+  save_desktop_session(&rows);
+}
+
+// The rows are kept on every request, so nothing is lost on restart.
+fn save_desktop_session(words: &[String]) {
+  let result = SessionStore::open_default().and_then(|store| {
+    let mut session = store.load_or_new(DESKTOP_SESSION, Mode::Solve)?;
+    session.clear();
+    session.add_rows(words.to_vec());
+    store.save(&session)
+  });
+  if let Err(err) = result {
+    eprintln!("Session is not saved: {}", err);
+  }
+}
+
+#[tauri::command]
+fn restore_session() -> Result<Session, String> {
+  let store = SessionStore::open_default().map_err(|err| err.to_string())?;
+  store.load_or_new(DESKTOP_SESSION, Mode::Solve).map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+fn daily_word(date: Option<String>, salt: Option<String>) -> Result<&'static str, String> {
+  let date = match date {
+    Some(date) => NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|err| err.to_string())?,
+    None => Local::now().date_naive(),
+  };
+  Ok(word_of_the_day(date, &salt.unwrap_or_default()))
+}
 
 #[tauri::command]
-fn get_suggestions(words: Vec<String>) -> Vec<&'static str> {
-  guess_word(strings_to_words(words))
+fn get_board_suggestions(boards: Vec<Vec<String>>) -> Result<BoardSuggestions, Error> {
+  let rows = boards.iter().map(|rows| parse_rows(rows)).collect::<Result<Vec<_>, _>>()?;
+  let multiboard = MultiBoard::from_boards(rows)?;
+  Ok(BoardSuggestions {
+    candidates: multiboard.candidates(),
+    solved: multiboard.boards().iter().map(|b| b.solved().map(String::from)).collect(),
+    recommended: multiboard.recommend(5),
+  })
 }
 
 fn main() {
   tauri::Builder::default()
-    .invoke_handler(tauri::generate_handler![get_suggestions])
+    .manage(commands::CurrentGame::default())
+    .invoke_handler(tauri::generate_handler![
+      get_suggestions,
+      daily_word,
+      get_board_suggestions,
+      restore_session,
+      save_session,
+      commands::solve,
+      commands::rank_guesses,
+      commands::explain_word,
+      commands::keyboard_state,
+      commands::import_game,
+      commands::new_game,
+      commands::play_guess,
+    ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
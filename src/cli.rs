@@ -0,0 +1,253 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand, ValueEnum};
+use tinkoff_guess_game_lib::daily::DEFAULT_WINDOW;
+use tinkoff_guess_game_lib::rank::DEFAULT_RANK_COUNT;
+use tinkoff_guess_game_lib::simulate::Strategy;
+
+use crate::output::Format;
+
+/// Helper for the "5 букв" game of Tinkoff bank.
+///
+/// The yellow letters are prefixed by `=`, the white letters by `?`,
+/// the gray letters have no prefixes: `с=удь=я`, `?игрок`.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Same as `suggest`, kept for compatibility
+    #[arg(short, long, action)]
+    pub suggest: bool,
+
+    /// Same as `solve`, kept for compatibility
+    #[arg(short, long, value_parser, num_args = 0.. , value_delimiter=' ')]
+    pub words: Vec<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text, global = true)]
+    pub format: Format,
+
+    /// Draw the tiles without colors, same as the NO_COLOR variable
+    #[arg(long, action, global = true)]
+    pub no_color: bool,
+
+    /// Keep the entered rows in the named session and continue it next time
+    #[arg(long, global = true)]
+    pub session: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Find the words matching the entered rows
+    Solve {
+        /// Entered rows, e.g. лента с=удь=я ?игрок
+        rows: Vec<String>,
+
+        /// Take the rows from the pasted game, `-` for stdin
+        #[arg(long, value_name = "FILE")]
+        import: Option<PathBuf>,
+
+        /// Take the colors from the screenshot of the app, the rows are then just the words;
+        /// the words are asked for each row if none are given
+        #[arg(long, value_name = "FILE", conflicts_with = "import")]
+        screenshot: Option<PathBuf>,
+    },
+    /// Suggest first words covering the most letters
+    Suggest {
+        /// How many groups of words to show
+        #[arg(short, long)]
+        count: Option<usize>,
+    },
+    /// Rank the next guesses by the information they give
+    Rank {
+        /// Entered rows
+        rows: Vec<String>,
+
+        /// How many guesses to show
+        #[arg(short, long, default_value_t = DEFAULT_RANK_COUNT)]
+        count: usize,
+    },
+    /// Explain why the word is or isn't a good next guess
+    Explain {
+        /// The word to explain
+        word: String,
+
+        /// Entered rows
+        rows: Vec<String>,
+    },
+    /// Play the game against a secret word from the dictionary
+    Play {
+        /// Seed to pick the secret word
+        #[arg(long, conflicts_with_all = ["date", "daily"])]
+        seed: Option<u64>,
+
+        /// The host avoids your guesses as long as possible instead of picking a word
+        #[arg(long, action, conflicts_with_all = ["seed", "date", "daily"])]
+        adversarial: bool,
+
+        /// Play the word of the day
+        #[arg(long, action)]
+        daily: bool,
+
+        /// Play the word of the given day, e.g. 2024-03-08
+        #[arg(long)]
+        date: Option<NaiveDate>,
+
+        /// Salt of the word of the day, share it with your team
+        #[arg(long, default_value = "")]
+        salt: String,
+
+        /// Days without repeating the word of the day
+        #[arg(long, default_value_t = DEFAULT_WINDOW)]
+        window: usize,
+    },
+    /// Play a strategy against many answers and show how it does
+    Simulate {
+        /// How to pick the guesses
+        #[arg(long, value_enum, default_value_t = StrategyArg::Entropy)]
+        strategy: StrategyArg,
+
+        /// Number of the random answers to play
+        #[arg(short, long, default_value_t = 100)]
+        games: usize,
+
+        /// Play every word of the dictionary instead of the random ones
+        #[arg(long, action, conflicts_with = "games")]
+        all: bool,
+
+        /// Seed to pick the answers
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Also play against the adversarial host
+        #[arg(long, action)]
+        worst_case: bool,
+    },
+    /// Serve the JSON API over HTTP
+    Serve {
+        /// Address to listen on, 0.0.0.0 to let the phones in the same network in
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// Port to listen on, 0 to pick any free one
+        #[arg(short, long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Answer line-delimited JSON-RPC 2.0 requests on stdin
+    Rpc,
+    /// Talk to the chat bot in the terminal, a command per line
+    Bot,
+    /// Look into the dictionary
+    Dict {
+        #[command(subcommand)]
+        action: DictAction,
+    },
+    /// Solve several boards sharing the same guesses (Dordle, Quordle)
+    Boards {
+        /// Rows of one board, repeat for every board: -b "лента с=удь=я" -b "л?ента судья"
+        #[arg(short, long = "board", required = true)]
+        boards: Vec<String>,
+
+        /// How many guesses to recommend
+        #[arg(short, long, default_value_t = 5)]
+        count: usize,
+    },
+    /// Show row by row where the played game lost time
+    Analyze {
+        /// The answer of the game
+        #[arg(short, long)]
+        answer: String,
+
+        /// Played words, the `=`/`?` markers are ignored
+        #[arg(required = true)]
+        rows: Vec<String>,
+    },
+    /// Show the statistics of the finished games
+    Stats,
+    /// Manage the saved sessions
+    Sessions {
+        #[command(subcommand)]
+        action: SessionAction,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum StrategyArg {
+    /// The guess giving the most information
+    Entropy,
+    /// The first matching word
+    FirstCandidate,
+}
+
+impl From<StrategyArg> for Strategy {
+    fn from(arg: StrategyArg) -> Strategy {
+        match arg {
+            StrategyArg::Entropy => Strategy::Entropy,
+            StrategyArg::FirstCandidate => Strategy::FirstCandidate,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DictAction {
+    /// Print all the words
+    List,
+    /// Check whether the words are in the dictionary
+    Check {
+        #[arg(required = true)]
+        words: Vec<String>,
+    },
+    /// Letter frequencies overall and by position, bigrams and words with repeated letters
+    Stats {
+        /// Entered rows, the statistics are then of the words still possible
+        rows: Vec<String>,
+
+        /// Word list to use instead of the built-in one, a word per line
+        #[arg(long, value_name = "FILE")]
+        dictionary: Option<PathBuf>,
+
+        /// How many bigrams to show
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+    },
+    /// Check the word list: the length, the letters, the repeats and `ё`
+    Validate {
+        /// Word list, a word per line, the built-in one if omitted
+        file: Option<PathBuf>,
+    },
+    /// Drop the repeated words of the list, the first ones are kept
+    Dedupe {
+        file: PathBuf,
+
+        /// Where to write the list instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Join the word lists, every word once in the order it first appears
+    Merge {
+        #[arg(required = true, num_args = 2..)]
+        files: Vec<PathBuf>,
+
+        /// Where to write the list instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Show the words added to and removed from the list
+    Diff {
+        old: PathBuf,
+        new: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SessionAction {
+    /// List the saved sessions
+    List,
+    /// Show the rows of the session
+    Show { name: String },
+    /// Delete the session
+    Delete { name: String },
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::{words, Letter};
+use crate::game::{colored_letters, normalize_guess, pattern_code, score, GuessError, Host};
+
+/// Host which never picks the answer up front (Absurdle-style).
+///
+/// After every guess it answers with the feedback that keeps the largest
+/// set of candidates, so the game lasts as long as possible.
+#[derive(Debug)]
+pub struct AdversarialGame {
+    candidates: Vec<&'static str>,
+    rows: Vec<Vec<Letter>>,
+}
+
+impl AdversarialGame {
+    pub fn new() -> AdversarialGame {
+        AdversarialGame { candidates: words::WORDLIST.to_vec(), rows: Vec::new() }
+    }
+
+    /// Words which are still consistent with every answered row.
+    pub fn candidates(&self) -> &[&'static str] {
+        &self.candidates
+    }
+}
+
+impl Default for AdversarialGame {
+    fn default() -> AdversarialGame {
+        AdversarialGame::new()
+    }
+}
+
+impl Host for AdversarialGame {
+    fn guess(&mut self, word: &str) -> Result<&[Letter], GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        let word = normalize_guess(word)?;
+
+        let mut groups: HashMap<u8, Vec<&'static str>> = HashMap::new();
+        for candidate in self.candidates.iter() {
+            groups.entry(pattern_code(&score(&word, candidate))).or_default().push(candidate);
+        }
+        // The largest group wins, ties go to the feedback revealing less.
+        let (_, candidates) = groups.into_iter()
+            .max_by(|(a_code, a), (b_code, b)| a.len().cmp(&b.len()).then(b_code.cmp(a_code)))
+            .unwrap();
+        let colors = score(&word, candidates[0]);
+        self.candidates = candidates;
+        self.rows.push(colored_letters(&word, &colors));
+        Ok(self.rows.last().unwrap())
+    }
+
+    fn rows(&self) -> &[Vec<Letter>] {
+        &self.rows
+    }
+
+    fn answer(&self) -> Option<&'static str> {
+        match self.candidates.as_slice() {
+            [answer] => Some(answer),
+            _ => None,
+        }
+    }
+
+    fn max_attempts(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Plays the strategy against the adversarial host and returns the number of
+/// guesses it needs in the worst case, `None` if it is not done within `limit`.
+///
+/// The strategy gets the rows played so far and the remaining candidates.
+pub fn worst_case<S>(mut strategy: S, limit: usize) -> Option<usize>
+where
+    S: FnMut(&[Vec<Letter>], &[&'static str]) -> &'static str,
+{
+    let mut game = AdversarialGame::new();
+    while game.attempts() < limit {
+        let guess = strategy(game.rows(), game.candidates());
+        game.guess(guess).ok()?;
+        if game.is_won() {
+            return Some(game.attempts());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::game::row_word;
+
+    #[test]
+    fn test_keeps_largest_group() {
+        let mut game = AdversarialGame::new();
+        let mut sizes: HashMap<u8, usize> = HashMap::new();
+        for word in words::WORDLIST.iter() {
+            *sizes.entry(pattern_code(&score("лента", word))).or_default() += 1;
+        }
+        game.guess("лента").unwrap();
+        assert_eq!(game.candidates().len(), *sizes.values().max().unwrap());
+        assert!(!game.is_won());
+        assert_eq!(game.answer(), None);
+    }
+
+    #[test]
+    fn test_candidates_match_feedback() {
+        let mut game = AdversarialGame::new();
+        game.guess("лента").unwrap();
+        game.guess("игрок").unwrap();
+        for row in game.rows() {
+            let word = row_word(row);
+            let colors: Vec<_> = row.iter().map(|l| l.color).collect();
+            assert!(game.candidates().iter().all(|c| score(&word, c) == colors));
+        }
+    }
+
+    #[test]
+    fn test_is_never_over_until_won() {
+        let mut game = AdversarialGame::new();
+        for _ in 0..10 {
+            game.guess("лента").unwrap();
+        }
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn test_worst_case() {
+        let attempts = worst_case(|_, candidates| candidates[0], 100).unwrap();
+        assert!(attempts > 1);
+        assert_eq!(worst_case(|_, candidates| candidates[0], 1), None);
+    }
+}
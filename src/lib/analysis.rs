@@ -0,0 +1,109 @@
+use serde::Serialize;
+
+use crate::{letters_to_string, words};
+use crate::game::{colored_letters, is_consistent, normalize_guess_in, score, GuessError};
+use crate::rank::{entropy, rank_guesses};
+
+/// How a single row of a played game went.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RowAnalysis {
+    /// The row in the `=`/`?` notation.
+    pub row: String,
+    /// Candidates left before and after the row.
+    pub before: usize,
+    pub after: usize,
+    /// Information expected from the guess and actually gained, in bits.
+    pub expected_bits: f64,
+    pub actual_bits: f64,
+    /// What the recommender would have played instead.
+    pub best_guess: &'static str,
+    pub best_bits: f64,
+}
+
+impl RowAnalysis {
+    /// Bits lost comparing to the best guess.
+    pub fn regret(&self) -> f64 {
+        (self.best_bits - self.expected_bits).max(0.0)
+    }
+}
+
+/// Goes through the game row by row over the whole dictionary.
+pub fn analyze(guesses: &[&str], answer: &str) -> Result<Vec<RowAnalysis>, GuessError> {
+    analyze_in(words::WORDLIST, guesses, answer)
+}
+
+/// Same as `analyze`, but only `words` may be the answer or the guess.
+pub fn analyze_in(words: &[&'static str], guesses: &[&str], answer: &str) -> Result<Vec<RowAnalysis>, GuessError> {
+    let answer = normalize_guess_in(words, answer)?;
+    let guesses = guesses.iter().map(|g| normalize_guess_in(words, g)).collect::<Result<Vec<_>, _>>()?;
+
+    let mut candidates: Vec<&'static str> = words.to_vec();
+    let mut res = Vec::new();
+    for guess in guesses.iter() {
+        let row = colored_letters(guess, &score(guess, &answer));
+        let after: Vec<&'static str> = candidates.iter().filter(|w| is_consistent(&row, w)).copied().collect();
+        let (best_guess, best_bits) = rank_guesses(words, &[candidates.clone()])[0];
+        res.push(RowAnalysis {
+            row: letters_to_string(&row),
+            before: candidates.len(),
+            after: after.len(),
+            expected_bits: entropy(guess, &candidates),
+            actual_bits: (candidates.len() as f64 / after.len().max(1) as f64).log2(),
+            best_guess,
+            best_bits,
+        });
+        candidates = after;
+        if *guess == answer {
+            break;
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const WORDS: [&str; 6] = ["мумия", "мамка", "лента", "игрок", "гурия", "фурия"];
+
+    #[test]
+    fn test_analyze() {
+        let rows = analyze_in(&WORDS, &["лента", "игрок", "мумия"], "мумия").unwrap();
+        assert_eq!(rows.len(), 3);
+
+        assert_eq!(rows[0].row, "лента");
+        assert_eq!((rows[0].before, rows[0].after), (6, 4));
+        assert!((rows[0].actual_bits - (6f64 / 4.0).log2()).abs() < 1e-9);
+        assert!(rows[0].best_bits >= rows[0].expected_bits);
+        assert!(rows[0].regret() > 0.0);
+
+        assert_eq!(rows[1].row, "?игрок");
+        assert_eq!((rows[1].before, rows[1].after), (4, 1));
+
+        // Nothing left to learn, the answer is the best guess.
+        assert_eq!(rows[2].row, "=м=у=м=и=я");
+        assert_eq!((rows[2].before, rows[2].after), (1, 1));
+        assert_eq!(rows[2].best_guess, "мумия");
+        assert_eq!(rows[2].regret(), 0.0);
+    }
+
+    #[test]
+    fn test_analyze_stops_on_answer() {
+        let rows = analyze_in(&WORDS, &["мумия", "лента"], "мумия").unwrap();
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[test]
+    fn test_analyze_unknown_word() {
+        assert_eq!(
+            analyze_in(&WORDS, &["ыыыыы"], "мумия").unwrap_err(),
+            GuessError::UnknownWord(String::from("ыыыыы")),
+        );
+        // Only the given words count, not the whole dictionary.
+        assert_eq!(
+            analyze_in(&WORDS, &["судья"], "мумия").unwrap_err(),
+            GuessError::UnknownWord(String::from("судья")),
+        );
+        assert_eq!(analyze_in(&["ыыыыы", "мумия"], &["ыыыыы"], "мумия").unwrap().len(), 1);
+    }
+}
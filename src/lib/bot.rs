@@ -0,0 +1,286 @@
+// Chat bot answering the rows with the candidates. Every chat user has own rows,
+// the messenger is hidden behind `Transport`, so an adapter only moves the messages.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, BufRead, Write};
+
+use crate::rank::best_guesses;
+use crate::solve::{candidates, try_push_row};
+
+pub const TOP_COUNT: usize = 5;
+/// Longer lists of the candidates are cut not to flood the chat, and `/top` asks no more.
+const LISTED_CANDIDATES: usize = 20;
+
+const HELP: &str = "/new - start a new game\n\
+    /row с=удь=я - add a row, `=` marks the yellow letters, `?` the white ones\n\
+    /top [count] - the best next guesses, up to 20\n\
+    /undo - remove the last row";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub user: String,
+    pub text: String,
+}
+
+impl Message {
+    pub fn new(user: &str, text: &str) -> Message {
+        Message { user: String::from(user), text: String::from(text) }
+    }
+}
+
+/// The messenger side of the bot.
+pub trait Transport {
+    /// The next message to the bot, `None` once there will be no more.
+    fn receive(&mut self) -> io::Result<Option<Message>>;
+
+    fn send(&mut self, user: &str, text: &str) -> io::Result<()>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    New,
+    Row(String),
+    Top(Option<usize>),
+    Undo,
+    Help,
+}
+
+impl Command {
+    /// Parses the message text, `Err` holds the reply for the wrong command.
+    /// The `@botname` suffix which the group chats add to the commands is ignored.
+    pub fn parse(text: &str) -> Result<Command, String> {
+        let mut parts = text.split_whitespace();
+        let name = parts.next().unwrap_or_default();
+        let name = name.split('@').next().unwrap_or_default();
+        let args: Vec<&str> = parts.collect();
+        match (name, args.as_slice()) {
+            ("/new", []) => Ok(Command::New),
+            ("/row", [row]) => Ok(Command::Row(String::from(*row))),
+            ("/row", _) => Err(String::from("Send one row: /row с=удь=я")),
+            ("/top", []) => Ok(Command::Top(None)),
+            ("/top", [count]) => match count.parse() {
+                Ok(count) if (1..=LISTED_CANDIDATES).contains(&count) => Ok(Command::Top(Some(count))),
+                _ => Err(format!("The count must be from 1 to {}, not `{}`", LISTED_CANDIDATES, count)),
+            },
+            ("/undo", []) => Ok(Command::Undo),
+            ("/help" | "/start", _) => Ok(Command::Help),
+            _ => Err(format!("Unknown command `{}`\n{}", text.trim(), HELP)),
+        }
+    }
+}
+
+fn describe_candidates(words: &[&str]) -> String {
+    match words {
+        [] => String::from("No words match the rows, check the colors or /undo"),
+        [word] => format!("The answer is {}", word),
+        _ if words.len() > LISTED_CANDIDATES => format!(
+            "{} words left: {} and {} more",
+            words.len(),
+            words[..LISTED_CANDIDATES].join(", "),
+            words.len() - LISTED_CANDIDATES,
+        ),
+        _ => format!("{} words left: {}", words.len(), words.join(", ")),
+    }
+}
+
+/// Rows of every user in the `=`/`?` notation, a user without rows plays a new game.
+#[derive(Debug, Default)]
+pub struct Bot {
+    sessions: HashMap<String, Vec<String>>,
+}
+
+impl Bot {
+    pub fn new() -> Bot {
+        Bot::default()
+    }
+
+    pub fn rows(&self, user: &str) -> &[String] {
+        self.sessions.get(user).map(|rows| rows.as_slice()).unwrap_or_default()
+    }
+
+    /// The reply to the user's message.
+    pub fn handle(&mut self, user: &str, text: &str) -> String {
+        match Command::parse(text) {
+            Ok(command) => self.execute(user, command),
+            Err(reply) => reply,
+        }
+    }
+
+    fn execute(&mut self, user: &str, command: Command) -> String {
+        match command {
+            Command::New => {
+                self.sessions.insert(String::from(user), Vec::new());
+                String::from("New game, send the rows with /row")
+            },
+            Command::Row(row) => match try_push_row(self.sessions.entry(String::from(user)).or_default(), row) {
+                Ok(words) => describe_candidates(&words),
+                Err(err) => err.to_string(),
+            },
+            Command::Top(count) => {
+                let words = match candidates(self.rows(user)) {
+                    Ok(words) => words,
+                    Err(err) => return err.to_string(),
+                };
+                if words.is_empty() {
+                    return describe_candidates(&words);
+                }
+                best_guesses(&words, count.unwrap_or(TOP_COUNT)).iter()
+                    .enumerate()
+                    .map(|(idx, (word, bits))| format!("{}. {} {:.2}", idx + 1, word, bits))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            },
+            Command::Undo => {
+                let Some(row) = self.sessions.get_mut(user).and_then(|rows| rows.pop()) else {
+                    return String::from("There are no rows to undo");
+                };
+                match candidates(self.rows(user)) {
+                    Ok(words) => format!("Removed {}\n{}", row, describe_candidates(&words)),
+                    Err(err) => err.to_string(),
+                }
+            },
+            Command::Help => String::from(HELP),
+        }
+    }
+
+    /// Answers the messages until the transport runs out of them.
+    pub fn run<T: Transport>(&mut self, transport: &mut T) -> io::Result<()> {
+        while let Some(message) = transport.receive()? {
+            let reply = self.handle(&message.user, &message.text);
+            transport.send(&message.user, &reply)?;
+        }
+        Ok(())
+    }
+}
+
+/// Transport for the tests: the messages are queued up front, the replies are collected.
+#[derive(Debug, Default)]
+pub struct MemoryTransport {
+    incoming: VecDeque<Message>,
+    sent: Vec<Message>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> MemoryTransport {
+        MemoryTransport::default()
+    }
+
+    pub fn push(&mut self, user: &str, text: &str) {
+        self.incoming.push_back(Message::new(user, text));
+    }
+
+    /// The replies in the order they were sent.
+    pub fn sent(&self) -> &[Message] {
+        &self.sent
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn receive(&mut self) -> io::Result<Option<Message>> {
+        Ok(self.incoming.pop_front())
+    }
+
+    fn send(&mut self, user: &str, text: &str) -> io::Result<()> {
+        self.sent.push(Message::new(user, text));
+        Ok(())
+    }
+}
+
+/// Transport to try the bot in the terminal: every line is a message of the single user.
+pub struct ConsoleTransport<R, W> {
+    input: R,
+    output: W,
+    user: String,
+}
+
+impl<R: BufRead, W: Write> ConsoleTransport<R, W> {
+    pub fn new(input: R, output: W, user: &str) -> ConsoleTransport<R, W> {
+        ConsoleTransport { input, output, user: String::from(user) }
+    }
+}
+
+impl<R: BufRead, W: Write> Transport for ConsoleTransport<R, W> {
+    fn receive(&mut self) -> io::Result<Option<Message>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.input.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim().is_empty() {
+                return Ok(Some(Message::new(&self.user, line.trim())));
+            }
+        }
+    }
+
+    fn send(&mut self, _user: &str, text: &str) -> io::Result<()> {
+        writeln!(self.output, "{}", text)?;
+        self.output.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(Command::parse("/new"), Ok(Command::New));
+        assert_eq!(Command::parse(" /row с=удь=я "), Ok(Command::Row(String::from("с=удь=я"))));
+        assert_eq!(Command::parse("/top@guess_bot 3"), Ok(Command::Top(Some(3))));
+        assert_eq!(Command::parse("/undo"), Ok(Command::Undo));
+        assert!(Command::parse("/row").is_err());
+        assert!(Command::parse("/top 0").is_err());
+        assert_eq!(Command::parse("/top 20"), Ok(Command::Top(Some(20))));
+        assert!(Command::parse("/top 21").is_err());
+        assert!(Command::parse("лента").unwrap_err().starts_with("Unknown command `лента`"));
+    }
+
+    #[test]
+    fn test_sessions_per_user() {
+        let mut bot = Bot::new();
+        assert_eq!(bot.handle("anna", "/row лента").lines().count(), 1);
+        assert_eq!(bot.handle("anna", "/row с=удь=я"), "5 words left: гурия, курия, мумия, рупия, фурия");
+        assert_eq!(bot.handle("boris", "/row ?игрок").split(':').count(), 2);
+        assert_eq!(bot.rows("anna"), ["лента", "с=удь=я"]);
+        assert_eq!(bot.rows("boris"), ["?игрок"]);
+
+        assert_eq!(bot.handle("anna", "/top 2").lines().count(), 2);
+        assert!(bot.handle("anna", "/undo").starts_with("Removed с=удь=я\n"));
+        assert_eq!(bot.handle("anna", "/new"), "New game, send the rows with /row");
+        assert!(bot.rows("anna").is_empty());
+        assert_eq!(bot.handle("anna", "/undo"), "There are no rows to undo");
+    }
+
+    #[test]
+    fn test_wrong_row_is_not_kept() {
+        let mut bot = Bot::new();
+        bot.handle("anna", "/row лента");
+        assert_eq!(bot.handle("anna", "/row лент"), "Row of 5 letters is expected, but 4 given: лент");
+        assert_eq!(bot.rows("anna"), ["лента"]);
+    }
+
+    #[test]
+    fn test_run_with_memory_transport() {
+        let mut transport = MemoryTransport::new();
+        transport.push("anna", "/row лента");
+        transport.push("boris", "/help");
+        transport.push("anna", "/row с=удь=я");
+        transport.push("anna", "/row ?игрок");
+        Bot::new().run(&mut transport).unwrap();
+
+        let sent = transport.sent();
+        assert_eq!(sent.len(), 4);
+        assert_eq!(sent[1], Message::new("boris", HELP));
+        assert_eq!(sent[3], Message::new("anna", "The answer is мумия"));
+    }
+
+    #[test]
+    fn test_console_transport() {
+        let mut output = Vec::new();
+        let mut transport = ConsoleTransport::new("/row лента\n\n/row с=удь=я\n".as_bytes(), &mut output, "me");
+        Bot::new().run(&mut transport).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().last(), Some("5 words left: гурия, курия, мумия, рупия, фурия"));
+    }
+}
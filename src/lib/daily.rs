@@ -0,0 +1,171 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::{words, Error};
+
+pub const DEFAULT_WINDOW: usize = 365;
+
+/// Word of the day for everyone who uses the same salt.
+///
+/// Days are split into blocks of `window` days and the words into two halves by a
+/// hash, the halves take turns, so the neighbour blocks never share a word. Every
+/// day of a block takes the word of its half with the smallest hash of the salt, the
+/// day and the word, which isn't taken earlier in the block. The order of the lines
+/// in the dictionary does not matter, and a new word changes only the days of a block
+/// from the first one it is taken on.
+#[derive(Debug, Clone)]
+pub struct DailyWords {
+    salt: String,
+    window: usize,
+}
+
+impl DailyWords {
+    pub fn new(salt: &str, window: usize) -> Result<DailyWords, Error> {
+        let daily = DailyWords { salt: String::from(salt), window };
+        let odd = words::WORDLIST.iter().filter(|word| daily.half(word) == 1).count();
+        let max = odd.min(words::WORDLIST.len() - odd);
+        if window == 0 || window > max {
+            return Err(Error::WrongWindow { window, max });
+        }
+        Ok(daily)
+    }
+
+    pub fn word(&self, date: NaiveDate) -> &'static str {
+        let window = self.window as i64;
+        let day = date.num_days_from_ce() as i64;
+        let pos = day.rem_euclid(window) as usize;
+        self.block_words(words::WORDLIST, day.div_euclid(window), pos + 1)[pos]
+    }
+
+    /// Words of the first `days` days of the block.
+    fn block_words(&self, words: &[&'static str], block: i64, days: usize) -> Vec<&'static str> {
+        let half = block.rem_euclid(2) as u64;
+        let mut left: Vec<&'static str> = words.iter().copied().filter(|word| self.half(word) == half).collect();
+        let salted = fnv(FNV_OFFSET, self.salt.as_bytes());
+        let first_day = block * self.window as i64;
+        (first_day..first_day + days as i64).map(|day| {
+            let prefix = fnv(salted, &day.to_le_bytes());
+            let (idx, _) = left.iter()
+                .enumerate()
+                .min_by_key(|(_, word)| (fnv(prefix, word.as_bytes()), **word))
+                .expect("The window is longer than the half of the dictionary");
+            left.swap_remove(idx)
+        }).collect()
+    }
+
+    // The high bit, the low one of FNV-1a is the same for every salt.
+    fn half(&self, word: &str) -> u64 {
+        fnv(fnv(FNV_OFFSET, self.salt.as_bytes()), word.as_bytes()) >> 63
+    }
+}
+
+impl Default for DailyWords {
+    fn default() -> DailyWords {
+        DailyWords { salt: String::new(), window: DEFAULT_WINDOW }
+    }
+}
+
+pub fn word_of_the_day(date: NaiveDate, salt: &str) -> &'static str {
+    DailyWords { salt: String::from(salt), ..Default::default() }.word(date)
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+// FNV-1a, the words must not depend on the platform or the compiler version.
+fn fnv(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_word_of_the_day_is_stable() {
+        let day = date("2024-03-08");
+        assert_eq!(word_of_the_day(day, "office"), word_of_the_day(day, "office"));
+        assert!(words::WORDLIST.contains(&word_of_the_day(day, "office")));
+    }
+
+    #[test]
+    fn test_word_of_the_day_is_pinned() {
+        // The words of the days already played change only if the dictionary does.
+        assert_eq!(word_of_the_day(date("2024-03-08"), ""), "регби");
+        assert_eq!(word_of_the_day(date("2024-03-08"), "office"), "кожан");
+    }
+
+    #[test]
+    fn test_salt_changes_words() {
+        let start = date("2024-01-01");
+        let plain: Vec<_> = (0..10).map(|n| word_of_the_day(start + chrono::Days::new(n), "")).collect();
+        let salted: Vec<_> = (0..10).map(|n| word_of_the_day(start + chrono::Days::new(n), "office")).collect();
+        assert_ne!(plain, salted);
+    }
+
+    #[test]
+    fn test_no_repeats_within_window() {
+        let daily = DailyWords::new("office", 30).unwrap();
+        // Walk across a block boundary.
+        let boundary = 30 * (date("2024-01-01").num_days_from_ce() / 30 + 1);
+        let start = NaiveDate::from_num_days_from_ce_opt(boundary - 40).unwrap();
+        let days: Vec<_> = (0..80).map(|n| daily.word(start + chrono::Days::new(n))).collect();
+        for window in days.windows(30) {
+            let unique: HashSet<_> = window.iter().collect();
+            assert_eq!(unique.len(), window.len());
+        }
+    }
+
+    #[test]
+    fn test_block_boundary_with_largest_window() {
+        let Err(Error::WrongWindow { max, .. }) = DailyWords::new("office", usize::MAX) else {
+            panic!("The window must be limited");
+        };
+        let daily = DailyWords::new("office", max).unwrap();
+        let previous: HashSet<_> = daily.block_words(words::WORDLIST, 7, max).into_iter().collect();
+        let current: HashSet<_> = daily.block_words(words::WORDLIST, 8, max).into_iter().collect();
+        assert_eq!((previous.len(), current.len()), (max, max));
+        assert!(previous.is_disjoint(&current));
+    }
+
+    #[test]
+    fn test_dictionary_order_does_not_matter() {
+        let daily = DailyWords::new("office", 30).unwrap();
+        let mut reversed = words::WORDLIST.to_vec();
+        reversed.reverse();
+        for block in 0..4 {
+            assert_eq!(daily.block_words(&reversed, block, 30), daily.block_words(words::WORDLIST, block, 30));
+        }
+    }
+
+    #[test]
+    fn test_new_word_keeps_days_before_it() {
+        let daily = DailyWords::new("office", 30).unwrap();
+        for block in 0..4 {
+            let before = daily.block_words(words::WORDLIST, block, 30);
+            // As if the word of the 11th day had just been added to the dictionary.
+            let added = before[10];
+            let without: Vec<_> = words::WORDLIST.iter().copied().filter(|word| *word != added).collect();
+            let after = daily.block_words(&without, block, 30);
+            assert_eq!(before[..10], after[..10]);
+            assert_ne!(before[10], after[10]);
+            let next_block = daily.block_words(&without, block + 1, 30);
+            assert_eq!(next_block, daily.block_words(words::WORDLIST, block + 1, 30));
+        }
+    }
+
+    #[test]
+    fn test_window_is_limited() {
+        assert_eq!(DailyWords::new("", 0).unwrap_err().code(), "wrong_window");
+        assert!(DailyWords::new("", words::WORDLIST.len()).is_err());
+        assert!(DailyWords::new("", DEFAULT_WINDOW).is_ok());
+    }
+}
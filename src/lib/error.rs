@@ -0,0 +1,76 @@
+use std::fmt;
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::game::GuessError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The row doesn't make a word of five letters.
+    WrongRow { row: String, letters: usize },
+    /// The rows contradict each other.
+    ConflictingRows(Vec<String>),
+    /// The pasted text doesn't make a game.
+    Import(String),
+    Guess(GuessError),
+    /// The days without repeats don't fit the dictionary.
+    WrongWindow { window: usize, max: usize },
+}
+
+impl Error {
+    /// Stable code for the scripts, the message may change.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::WrongRow { .. } => "wrong_row",
+            Error::ConflictingRows(_) => "conflicting_rows",
+            Error::Import(_) => "wrong_import",
+            Error::Guess(err) => err.code(),
+            Error::WrongWindow { .. } => "wrong_window",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::WrongRow { row, letters } => write!(f, "Row of 5 letters is expected, but {} given: {}", letters, row),
+            Error::ConflictingRows(errors) => write!(f, "{}", errors.join("; ")),
+            Error::Import(err) => write!(f, "Can't import the game: {}", err),
+            Error::Guess(err) => write!(f, "{}", err),
+            Error::WrongWindow { window, max } => write!(f, "Window from 1 to {} days is expected, but {} given", max, window),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<GuessError> for Error {
+    fn from(err: GuessError) -> Error {
+        Error::Guess(err)
+    }
+}
+
+/// Serialized as `{"code": ..., "message": ...}`.
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Error", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_json() {
+        let err = Error::WrongRow { row: String::from("лент"), letters: 4 };
+        assert_eq!(
+            serde_json::to_string(&err).unwrap(),
+            r#"{"code":"wrong_row","message":"Row of 5 letters is expected, but 4 given: лент"}"#,
+        );
+        assert_eq!(Error::from(GuessError::GameOver).code(), "game_over");
+    }
+}
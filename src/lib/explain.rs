@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::{parse_rows, stat_from_rows, Error, Stat};
+use crate::game::{is_known_word, pattern_code, score, GuessError, WORD_LENGTH};
+use crate::rank::entropy;
+use crate::solve::candidates;
+
+/// Why the word is or isn't a good guess after the rows.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Explanation {
+    pub word: String,
+    pub in_dictionary: bool,
+    /// Whether the word may still be the answer.
+    pub candidate: bool,
+    /// The rows the word contradicts to.
+    pub conflicts: Vec<String>,
+    pub remaining: usize,
+    /// Expected information of the word as the next guess, in bits.
+    pub bits: f64,
+    /// Number of the different feedbacks the word may get.
+    pub groups: usize,
+    /// Candidates left in the worst case.
+    pub largest_group: usize,
+}
+
+fn conflicts(stat: &Stat, word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut res = Vec::new();
+
+    let mut gray: Vec<char> = stat.gray_letters.iter().filter(|ch| chars.contains(ch)).copied().collect();
+    gray.sort();
+    res.extend(gray.iter().map(|ch| format!("Letter `{}` is not in the answer", ch)));
+
+    let mut yellow: Vec<(usize, char)> = stat.yellow_letters.iter().map(|(idx, ch)| (*idx, *ch)).collect();
+    yellow.sort();
+    for (idx, ch) in yellow {
+        if chars.get(idx) != Some(&ch) {
+            res.push(format!("Letter `{}` must be at position {}", ch, idx + 1));
+        }
+    }
+
+    let mut white: Vec<char> = stat.white_letters.values().flatten().filter(|ch| !chars.contains(ch)).copied().collect();
+    white.sort();
+    white.dedup();
+    res.extend(white.iter().map(|ch| format!("Letter `{}` must be in the answer", ch)));
+    res
+}
+
+pub fn explain_word(word: &str, rows: &[String]) -> Result<Explanation, Error> {
+    let word = word.trim().to_lowercase();
+    let length = word.chars().count();
+    if length != WORD_LENGTH {
+        return Err(Error::Guess(GuessError::WrongLength(length)));
+    }
+    let stat = stat_from_rows(&parse_rows(rows)?)?;
+    let candidates = candidates(rows)?;
+
+    let mut groups: HashMap<u8, usize> = HashMap::new();
+    for candidate in candidates.iter() {
+        *groups.entry(pattern_code(&score(&word, candidate))).or_default() += 1;
+    }
+
+    Ok(Explanation {
+        in_dictionary: is_known_word(&word),
+        candidate: candidates.contains(&word.as_str()),
+        conflicts: conflicts(&stat, &word),
+        remaining: candidates.len(),
+        bits: entropy(&word, &candidates),
+        groups: groups.len(),
+        largest_group: groups.values().max().copied().unwrap_or(0),
+        word,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strings;
+
+    #[test]
+    fn test_explain_candidate() {
+        let explanation = explain_word("Мумия", &strings(&["лента", "с=удь=я"])).unwrap();
+        assert_eq!(explanation.word, "мумия");
+        assert!(explanation.in_dictionary);
+        assert!(explanation.candidate);
+        assert!(explanation.conflicts.is_empty());
+        assert_eq!(explanation.remaining, 5);
+        assert!(explanation.bits > 0.0);
+    }
+
+    #[test]
+    fn test_explain_conflicts() {
+        let explanation = explain_word("лента", &strings(&["лента", "с=удь=я", "?игрок"])).unwrap();
+        assert!(!explanation.candidate);
+        assert_eq!(explanation.conflicts, vec![
+            "Letter `а` is not in the answer",
+            "Letter `е` is not in the answer",
+            "Letter `л` is not in the answer",
+            "Letter `н` is not in the answer",
+            "Letter `т` is not in the answer",
+            "Letter `у` must be at position 2",
+            "Letter `я` must be at position 5",
+            "Letter `и` must be in the answer",
+        ]);
+        assert_eq!(explanation.remaining, 1);
+        assert_eq!((explanation.groups, explanation.largest_group), (1, 1));
+    }
+
+    #[test]
+    fn test_explain_wrong_length() {
+        assert_eq!(explain_word("мумиями", &[]).unwrap_err().code(), "wrong_length");
+    }
+
+    #[test]
+    fn test_explain_without_rows() {
+        let explanation = explain_word("ыыыыы", &[]).unwrap();
+        assert!(!explanation.in_dictionary);
+        assert!(!explanation.candidate);
+        assert!(explanation.conflicts.is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+// C API for the native apps, built with the `ffi` feature. The strings are UTF-8,
+// the lists come as one string with a line per item. `include/tinkoff_guess_game.h`
+// is generated from this file by the build script.
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use crate::Error;
+use crate::rank::best_guesses;
+use crate::solve::{candidates, try_push_row};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TggStatus {
+    Ok = 0,
+    /// The row doesn't make a word of five letters.
+    WrongRow = 1,
+    /// The row contradicts the previous ones.
+    ConflictingRows = 2,
+    /// A null pointer or not UTF-8 string is given.
+    InvalidArgument = 3,
+}
+
+/// The entered rows and the words which may still be the answer.
+pub struct TggSession {
+    rows: Vec<String>,
+    candidates: Vec<&'static str>,
+    last_error: Option<CString>,
+}
+
+fn into_c_string(string: String) -> *mut c_char {
+    // The words never contain NUL, neither do the numbers.
+    CString::new(string).map(CString::into_raw).unwrap_or(ptr::null_mut())
+}
+
+/// Creates an empty session, free it with `tgg_session_free`.
+#[no_mangle]
+pub extern "C" fn tgg_session_new() -> *mut TggSession {
+    let session = TggSession { rows: Vec::new(), candidates: candidates(&[]).unwrap(), last_error: None };
+    Box::into_raw(Box::new(session))
+}
+
+/// # Safety
+///
+/// The session must come from `tgg_session_new` and not be used afterwards. Null is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn tgg_session_free(session: *mut TggSession) {
+    if !session.is_null() {
+        drop(Box::from_raw(session));
+    }
+}
+
+/// Adds the row in the `=`/`?` notation, e.g. `с=удь=я`. The row is kept only if it agrees
+/// with the previous ones, otherwise `tgg_session_last_error` tells what is wrong.
+///
+/// # Safety
+///
+/// The session must be alive, the row must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn tgg_session_add_row(session: *mut TggSession, row: *const c_char) -> TggStatus {
+    let Some(session) = session.as_mut() else {
+        return TggStatus::InvalidArgument;
+    };
+    let row = match (!row.is_null()).then(|| CStr::from_ptr(row).to_str()) {
+        Some(Ok(row)) => row,
+        _ => {
+            session.last_error = CString::new("The row is not a UTF-8 string").ok();
+            return TggStatus::InvalidArgument;
+        },
+    };
+    match try_push_row(&mut session.rows, String::from(row)) {
+        Ok(words) => {
+            session.candidates = words;
+            session.last_error = None;
+            TggStatus::Ok
+        },
+        Err(err) => {
+            let status = match err {
+                Error::WrongRow { .. } => TggStatus::WrongRow,
+                _ => TggStatus::ConflictingRows,
+            };
+            session.last_error = CString::new(err.to_string()).ok();
+            status
+        },
+    }
+}
+
+/// Message of the last failed `tgg_session_add_row`, null if it succeeded.
+/// The string belongs to the session and lives until the next call.
+///
+/// # Safety
+///
+/// The session must be alive.
+#[no_mangle]
+pub unsafe extern "C" fn tgg_session_last_error(session: *const TggSession) -> *const c_char {
+    match session.as_ref().and_then(|session| session.last_error.as_ref()) {
+        Some(err) => err.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// # Safety
+///
+/// The session must be alive.
+#[no_mangle]
+pub unsafe extern "C" fn tgg_session_candidate_count(session: *const TggSession) -> usize {
+    session.as_ref().map_or(0, |session| session.candidates.len())
+}
+
+/// The words which may still be the answer, a line per word. Free with `tgg_string_free`.
+///
+/// # Safety
+///
+/// The session must be alive.
+#[no_mangle]
+pub unsafe extern "C" fn tgg_session_candidates(session: *const TggSession) -> *mut c_char {
+    match session.as_ref() {
+        Some(session) => into_c_string(session.candidates.join("\n")),
+        None => ptr::null_mut(),
+    }
+}
+
+/// The best next guesses as `word<TAB>bits` lines, best first. Free with `tgg_string_free`.
+/// Ranking for an empty session goes over the whole dictionary and takes a while.
+///
+/// # Safety
+///
+/// The session must be alive.
+#[no_mangle]
+pub unsafe extern "C" fn tgg_session_rank(session: *const TggSession, count: usize) -> *mut c_char {
+    match session.as_ref() {
+        Some(session) => {
+            let lines: Vec<String> = best_guesses(&session.candidates, count).iter()
+                .map(|(word, bits)| format!("{}\t{:.4}", word, bits))
+                .collect();
+            into_c_string(lines.join("\n"))
+        },
+        None => ptr::null_mut(),
+    }
+}
+
+/// # Safety
+///
+/// The string must come from this library and not be used afterwards. Null is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn tgg_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn c_str(string: &str) -> CString {
+        CString::new(string).unwrap()
+    }
+
+    unsafe fn take_string(string: *mut c_char) -> String {
+        let res = CStr::from_ptr(string).to_str().unwrap().to_string();
+        tgg_string_free(string);
+        res
+    }
+
+    #[test]
+    fn test_session() {
+        unsafe {
+            let session = tgg_session_new();
+            assert_eq!(tgg_session_add_row(session, c_str("лента").as_ptr()), TggStatus::Ok);
+            assert_eq!(tgg_session_add_row(session, c_str("с=удь=я").as_ptr()), TggStatus::Ok);
+            assert_eq!(tgg_session_candidate_count(session), 5);
+            assert_eq!(take_string(tgg_session_candidates(session)), "гурия\nкурия\nмумия\nрупия\nфурия");
+            assert_eq!(take_string(tgg_session_rank(session, 2)).lines().count(), 2);
+            tgg_session_free(session);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        unsafe {
+            let session = tgg_session_new();
+            assert!(tgg_session_last_error(session).is_null());
+            assert_eq!(tgg_session_add_row(session, c_str("лент").as_ptr()), TggStatus::WrongRow);
+            let err = CStr::from_ptr(tgg_session_last_error(session)).to_str().unwrap();
+            assert_eq!(err, "Row of 5 letters is expected, but 4 given: лент");
+            assert_eq!(tgg_session_add_row(session, ptr::null()), TggStatus::InvalidArgument);
+            assert_eq!(tgg_session_candidate_count(session), crate::words::WORD_COUNT);
+            assert_eq!(tgg_session_add_row(ptr::null_mut(), c_str("лента").as_ptr()), TggStatus::InvalidArgument);
+            tgg_session_free(session);
+        }
+    }
+}
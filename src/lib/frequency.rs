@@ -0,0 +1,121 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+
+use crate::game::WORD_LENGTH;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LetterFrequency {
+    pub letter: char,
+    /// Occurrences over all the words.
+    pub count: usize,
+    /// Occurrences at every position.
+    pub positions: Vec<usize>,
+    /// Words with the letter.
+    pub words: usize,
+    /// Share of the words with the letter.
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BigramFrequency {
+    pub bigram: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DictionaryStats {
+    pub words: usize,
+    /// The most frequent letters first.
+    pub letters: Vec<LetterFrequency>,
+    /// Pairs of the adjacent letters, the most frequent first.
+    pub bigrams: Vec<BigramFrequency>,
+    /// Words with a letter more than once, they tell less as guesses.
+    pub repeated_letters: Vec<String>,
+}
+
+/// Letter statistics of the words, e.g. of the dictionary or of the candidates.
+/// Only the letters count, so `хи-хи` has the `хи` bigram twice and no others.
+pub fn dictionary_stats(words: &[&str]) -> DictionaryStats {
+    let mut letters: BTreeMap<char, LetterFrequency> = BTreeMap::new();
+    let mut bigrams: BTreeMap<String, usize> = BTreeMap::new();
+    let mut repeated_letters = Vec::new();
+
+    for word in words {
+        let chars: Vec<char> = word.chars().collect();
+        let mut seen = HashSet::new();
+        for (idx, ch) in chars.iter().enumerate().filter(|(_, ch)| ch.is_alphabetic()) {
+            let letter = letters.entry(*ch).or_insert_with(|| LetterFrequency {
+                letter: *ch,
+                count: 0,
+                positions: vec![0; WORD_LENGTH],
+                words: 0,
+                share: 0.0,
+            });
+            letter.count += 1;
+            if let Some(count) = letter.positions.get_mut(idx) {
+                *count += 1;
+            }
+            if seen.insert(*ch) {
+                letter.words += 1;
+            }
+        }
+        if seen.len() < chars.iter().filter(|ch| ch.is_alphabetic()).count() {
+            repeated_letters.push(String::from(*word));
+        }
+        for pair in chars.windows(2).filter(|pair| pair.iter().all(|ch| ch.is_alphabetic())) {
+            *bigrams.entry(pair.iter().collect()).or_default() += 1;
+        }
+    }
+
+    let mut letters: Vec<LetterFrequency> = letters.into_values().map(|mut letter| {
+        letter.share = letter.words as f64 / words.len() as f64;
+        letter
+    }).collect();
+    // The maps keep the letters sorted, the stable sort keeps them so for the same counts.
+    letters.sort_by_key(|letter| Reverse(letter.count));
+    let mut bigrams: Vec<BigramFrequency> = bigrams.into_iter().map(|(bigram, count)| BigramFrequency { bigram, count }).collect();
+    bigrams.sort_by_key(|bigram| Reverse(bigram.count));
+
+    DictionaryStats { words: words.len(), letters, bigrams, repeated_letters }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dictionary_stats() {
+        let stats = dictionary_stats(&["мумия", "мамка", "лента"]);
+        assert_eq!(stats.words, 3);
+        assert_eq!(stats.letters[0], LetterFrequency {
+            letter: 'м',
+            count: 4,
+            positions: vec![2, 0, 2, 0, 0],
+            words: 2,
+            share: 2.0 / 3.0,
+        });
+        assert_eq!(stats.letters[1].letter, 'а');
+        assert_eq!(stats.repeated_letters, vec!["мумия", "мамка"]);
+        assert_eq!(stats.bigrams[0], BigramFrequency { bigram: String::from("ам"), count: 1 });
+        assert_eq!(stats.bigrams.len(), 12);
+    }
+
+    #[test]
+    fn test_letters_only() {
+        let stats = dictionary_stats(&["хи-хи"]);
+        assert_eq!(stats.letters.len(), 2);
+        assert_eq!(stats.letters[0].letter, 'и');
+        assert_eq!(stats.letters[1].positions, vec![1, 0, 0, 1, 0]);
+        assert_eq!(stats.bigrams, vec![BigramFrequency { bigram: String::from("хи"), count: 2 }]);
+        assert_eq!(stats.repeated_letters, vec!["хи-хи"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let stats = dictionary_stats(&[]);
+        assert_eq!(stats.words, 0);
+        assert!(stats.letters.is_empty());
+    }
+}
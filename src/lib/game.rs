@@ -0,0 +1,305 @@
+use std::fmt;
+
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "os-rng")]
+use rand::thread_rng;
+use rand::rngs::StdRng;
+
+use crate::{find_matches, words, Color, Letter, Stat};
+
+pub const MAX_ATTEMPTS: usize = 6;
+pub const WORD_LENGTH: usize = 5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuessError {
+    WrongLength(usize),
+    UnknownWord(String),
+    GameOver,
+}
+
+impl GuessError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            GuessError::WrongLength(_) => "wrong_length",
+            GuessError::UnknownWord(_) => "unknown_word",
+            GuessError::GameOver => "game_over",
+        }
+    }
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::WrongLength(len) => write!(f, "Word of {} letters is expected, but {} given", WORD_LENGTH, len),
+            GuessError::UnknownWord(word) => write!(f, "Word `{}` is not in the dictionary", word),
+            GuessError::GameOver => write!(f, "The game is over"),
+        }
+    }
+}
+
+/// Colors of the `guess` letters for the hidden `answer`.
+/// Every answer letter can color only one guess letter, exact matches go first.
+pub fn score(guess: &str, answer: &str) -> Vec<Color> {
+    let guess: Vec<char> = guess.chars().collect();
+    let mut answer: Vec<Option<char>> = answer.chars().map(Some).collect();
+    let mut colors = vec![Color::Gray; guess.len()];
+
+    for (idx, ch) in guess.iter().enumerate() {
+        if answer.get(idx) == Some(&Some(*ch)) {
+            colors[idx] = Color::Yellow;
+            answer[idx] = None;
+        }
+    }
+    for (idx, ch) in guess.iter().enumerate() {
+        if colors[idx] == Color::Yellow {
+            continue;
+        }
+        if let Some(pos) = answer.iter().position(|a| *a == Some(*ch)) {
+            colors[idx] = Color::White;
+            answer[pos] = None;
+        }
+    }
+    colors
+}
+
+/// Packs the colors into a single number, handy to group words by feedback.
+pub fn pattern_code(colors: &[Color]) -> u8 {
+    colors.iter().fold(0, |code, color| code * 3 + match color {
+        Color::Gray => 0,
+        Color::White => 1,
+        Color::Yellow => 2,
+    })
+}
+
+/// Builds a row from the word and the colors of its letters.
+pub fn colored_letters(word: &str, colors: &[Color]) -> Vec<Letter> {
+    word.chars().zip(colors.iter()).map(|(letter, color)| Letter::new(letter, *color)).collect()
+}
+
+/// The guessed word of the row.
+pub fn row_word(row: &[Letter]) -> String {
+    row.iter().map(|l| l.letter).collect()
+}
+
+pub fn is_row_solved(row: &[Letter]) -> bool {
+    !row.is_empty() && row.iter().all(|l| l.color == Color::Yellow)
+}
+
+/// Whether the row could be played when `word` is the answer.
+pub fn is_consistent(row: &[Letter], word: &str) -> bool {
+    let colors: Vec<Color> = row.iter().map(|l| l.color).collect();
+    score(&row_word(row), word) == colors
+}
+
+/// Dictionary words which could be the answer after all the rows.
+pub fn consistent_words(rows: &[Vec<Letter>]) -> Vec<&'static str> {
+    let mut stat = Stat::default();
+    rows.iter().for_each(|row| stat.add_row(row));
+    find_matches(&stat, rows)
+}
+
+pub fn is_known_word(word: &str) -> bool {
+    words::WORDLIST.binary_search(&word).is_ok()
+}
+
+/// Checks the guess and brings it to the dictionary form.
+pub fn normalize_guess(word: &str) -> Result<String, GuessError> {
+    normalize_with(word, is_known_word)
+}
+
+/// Same as `normalize_guess`, but the guess must be one of `words`.
+pub fn normalize_guess_in(words: &[&str], word: &str) -> Result<String, GuessError> {
+    normalize_with(word, |word| words.contains(&word))
+}
+
+fn normalize_with(word: &str, is_known: impl Fn(&str) -> bool) -> Result<String, GuessError> {
+    let word = word.trim().to_lowercase();
+    let len = word.chars().count();
+    if len != WORD_LENGTH {
+        return Err(GuessError::WrongLength(len));
+    }
+    if !is_known(&word) {
+        return Err(GuessError::UnknownWord(word));
+    }
+    Ok(word)
+}
+
+/// The side of the game which answers the guesses.
+pub trait Host {
+    fn guess(&mut self, word: &str) -> Result<&[Letter], GuessError>;
+
+    fn rows(&self) -> &[Vec<Letter>];
+
+    /// The answer, once the host has committed to it.
+    fn answer(&self) -> Option<&'static str>;
+
+    fn max_attempts(&self) -> Option<usize> {
+        Some(MAX_ATTEMPTS)
+    }
+
+    fn attempts(&self) -> usize {
+        self.rows().len()
+    }
+
+    fn is_won(&self) -> bool {
+        self.rows().last().is_some_and(|row| is_row_solved(row))
+    }
+
+    fn is_over(&self) -> bool {
+        self.is_won() || self.max_attempts().is_some_and(|max| self.attempts() >= max)
+    }
+
+    /// Emoji grid to share the result without spoiling the answer.
+    fn share_grid(&self) -> String {
+        let attempts = if self.is_won() { self.attempts().to_string() } else { String::from("X") };
+        let mut grid = match self.max_attempts() {
+            Some(max) => format!("5 букв {}/{}", attempts, max),
+            None => format!("5 букв {}", attempts),
+        };
+        for row in self.rows().iter() {
+            grid.push('\n');
+            grid.extend(row.iter().map(|l| match l.color {
+                Color::Yellow => '🟨',
+                Color::White => '⬜',
+                Color::Gray => '⬛',
+            }));
+        }
+        grid
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    answer: &'static str,
+    rows: Vec<Vec<Letter>>,
+}
+
+impl Game {
+    pub fn new(answer: &'static str) -> Game {
+        Game { answer, rows: Vec::new() }
+    }
+
+    #[cfg(feature = "os-rng")]
+    pub fn random() -> Game {
+        Game::pick(&mut thread_rng())
+    }
+
+    pub fn with_seed(seed: u64) -> Game {
+        Game::pick(&mut StdRng::seed_from_u64(seed))
+    }
+
+    fn pick<R: Rng>(rng: &mut R) -> Game {
+        let n = rng.gen_range(0..words::WORDLIST.len());
+        Game::new(words::WORDLIST[n])
+    }
+}
+
+impl Host for Game {
+    fn guess(&mut self, word: &str) -> Result<&[Letter], GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        let word = normalize_guess(word)?;
+        let row = colored_letters(&word, &score(&word, self.answer));
+        self.rows.push(row);
+        Ok(self.rows.last().unwrap())
+    }
+
+    fn rows(&self) -> &[Vec<Letter>] {
+        &self.rows
+    }
+
+    fn answer(&self) -> Option<&'static str> {
+        Some(self.answer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        assert_eq!(score("мумия", "мумия"), vec![Color::Yellow; 5]);
+        assert_eq!(score("лента", "мумия"), vec![Color::Gray; 5]);
+        assert_eq!(
+            score("игрок", "мумия"),
+            vec![Color::White, Color::Gray, Color::Gray, Color::Gray, Color::Gray],
+        );
+    }
+
+    #[test]
+    fn test_score_repeated_letters() {
+        // Only one `м` of the guess can be colored for the single `м` of the answer.
+        assert_eq!(
+            score("мумия", "смазь"),
+            vec![Color::White, Color::Gray, Color::Gray, Color::Gray, Color::Gray],
+        );
+        assert_eq!(
+            score("мумия", "мамка"),
+            vec![Color::Yellow, Color::Gray, Color::Yellow, Color::Gray, Color::Gray],
+        );
+    }
+
+    #[test]
+    fn test_pattern_code() {
+        assert_eq!(pattern_code(&[Color::Gray; 5]), 0);
+        assert_eq!(pattern_code(&[Color::Yellow; 5]), 242);
+        assert_eq!(pattern_code(&score("игрок", "мумия")), 81);
+    }
+
+    #[test]
+    fn test_consistent_words() {
+        let mut game = Game::new("мумия");
+        game.guess("лента").unwrap();
+        game.guess("игрок").unwrap();
+        let words = consistent_words(game.rows());
+        assert!(words.contains(&"мумия"));
+        assert!(words.iter().all(|w| is_consistent(&game.rows()[1], w)));
+        assert!(!is_consistent(&game.rows()[1], "игрок"));
+    }
+
+    #[test]
+    fn test_guess_validation() {
+        let mut game = Game::new("мумия");
+        assert_eq!(game.guess("мума").unwrap_err(), GuessError::WrongLength(4));
+        assert_eq!(game.guess("ыыыыы").unwrap_err(), GuessError::UnknownWord(String::from("ыыыыы")));
+        assert_eq!(game.attempts(), 0);
+    }
+
+    #[test]
+    fn test_game_won() {
+        let mut game = Game::new("мумия");
+        game.guess("лента").unwrap();
+        game.guess("Мумия").unwrap();
+        assert!(game.is_won());
+        assert!(game.is_over());
+        assert_eq!(game.guess("лента").unwrap_err(), GuessError::GameOver);
+        assert_eq!(game.share_grid(), "5 букв 2/6\n⬛⬛⬛⬛⬛\n🟨🟨🟨🟨🟨");
+    }
+
+    #[test]
+    fn test_game_lost() {
+        let mut game = Game::new("мумия");
+        for _ in 0..MAX_ATTEMPTS {
+            game.guess("лента").unwrap();
+        }
+        assert!(!game.is_won());
+        assert!(game.is_over());
+        assert!(game.share_grid().starts_with("5 букв X/6\n"));
+    }
+
+    #[test]
+    fn test_is_known_word() {
+        // The lookup is a binary search over the list sorted by the build script.
+        assert!(words::WORDLIST.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(is_known_word(words::WORDLIST[0]));
+        assert!(is_known_word("мумия"));
+        assert!(!is_known_word("ыыыыы"));
+    }
+
+    #[test]
+    fn test_with_seed_is_deterministic() {
+        assert_eq!(Game::with_seed(42).answer(), Game::with_seed(42).answer());
+    }
+}
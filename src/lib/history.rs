@@ -0,0 +1,180 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::parse_rows;
+use crate::game::{consistent_words, MAX_ATTEMPTS};
+use crate::session::app_data_dir;
+
+/// A finished game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub date: NaiveDate,
+    /// Rows in the `=`/`?` notation.
+    pub rows: Vec<String>,
+    /// Unknown if the game is lost outside of the play mode.
+    pub answer: Option<String>,
+    pub attempts: usize,
+    pub won: bool,
+    pub helper_used: bool,
+}
+
+/// Finished games, one JSON object per line.
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new<P: AsRef<Path>>(path: P) -> History {
+        History { path: path.as_ref().to_path_buf() }
+    }
+
+    /// The history in the user data directory.
+    pub fn open_default() -> io::Result<History> {
+        Ok(History::new(app_data_dir()?.join("history.jsonl")))
+    }
+
+    pub fn append(&self, record: &GameRecord) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)
+    }
+
+    /// All the records in the order they were played.
+    pub fn load(&self) -> io::Result<Vec<GameRecord>> {
+        let data = match fs::read_to_string(&self.path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        data.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(io::Error::from))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Statistics {
+    pub played: usize,
+    pub won: usize,
+    pub win_rate: f64,
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// Number of the games won with 1, 2, ... attempts.
+    pub distribution: Vec<usize>,
+    /// Average number of the candidates left after the 1st, 2nd, ... row.
+    pub average_remaining: Vec<f64>,
+}
+
+pub fn statistics(records: &[GameRecord]) -> Statistics {
+    let mut records: Vec<&GameRecord> = records.iter().collect();
+    records.sort_by_key(|r| r.date);
+
+    let won = records.iter().filter(|r| r.won).count();
+    let mut distribution = vec![0; MAX_ATTEMPTS];
+    let mut streak = 0;
+    let mut max_streak = 0;
+    for record in records.iter() {
+        if record.won {
+            streak += 1;
+            max_streak = max_streak.max(streak);
+            if (1..=MAX_ATTEMPTS).contains(&record.attempts) {
+                distribution[record.attempts - 1] += 1;
+            }
+        } else {
+            streak = 0;
+        }
+    }
+
+    let mut remaining: Vec<(usize, usize)> = Vec::new();
+    for record in records.iter() {
+        // A record spoiled by hand still counts in the games, but not in the candidates.
+        let Ok(rows) = parse_rows(&record.rows) else {
+            continue;
+        };
+        for idx in 0..rows.len() {
+            if remaining.len() <= idx {
+                remaining.push((0, 0));
+            }
+            remaining[idx].0 += consistent_words(&rows[..=idx]).len();
+            remaining[idx].1 += 1;
+        }
+    }
+
+    Statistics {
+        played: records.len(),
+        won,
+        win_rate: if records.is_empty() { 0.0 } else { won as f64 / records.len() as f64 },
+        current_streak: streak,
+        max_streak,
+        distribution,
+        average_remaining: remaining.iter().map(|(total, games)| *total as f64 / *games as f64).collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(date: &str, rows: &[&str], won: bool) -> GameRecord {
+        GameRecord {
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            rows: rows.iter().map(|r| String::from(*r)).collect(),
+            answer: won.then(|| String::from("мумия")),
+            attempts: rows.len(),
+            won,
+            helper_used: false,
+        }
+    }
+
+    #[test]
+    fn test_history_file() {
+        let path = std::env::temp_dir().join(format!("tinkoff-history-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let history = History::new(&path);
+        assert_eq!(history.load().unwrap(), Vec::new());
+
+        let first = record("2024-03-08", &["лента", "=м=у=м=и=я"], true);
+        let second = record("2024-03-09", &["лента"], false);
+        history.append(&first).unwrap();
+        history.append(&second).unwrap();
+        assert_eq!(history.load().unwrap(), vec![first, second]);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_statistics() {
+        let stats = statistics(&[
+            record("2024-03-10", &["=м=у=м=и=я"], true),
+            record("2024-03-08", &["лента", "=м=у=м=и=я"], true),
+            record("2024-03-07", &["лента", "?игрок", "=м=у=м=и=я"], true),
+            record("2024-03-09", &["лента"], false),
+        ]);
+        assert_eq!(stats.played, 4);
+        assert_eq!(stats.won, 3);
+        assert_eq!(stats.win_rate, 0.75);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.max_streak, 2);
+        assert_eq!(stats.distribution, vec![1, 1, 1, 0, 0, 0]);
+        assert_eq!(stats.average_remaining.len(), 3);
+        assert_eq!(stats.average_remaining[2], 1.0);
+    }
+
+    #[test]
+    fn test_statistics_skip_wrong_rows() {
+        let stats = statistics(&[
+            record("2024-03-08", &["лент", "=м=у=м=и=я"], true),
+            record("2024-03-09", &["=м=у=м=и=я"], true),
+        ]);
+        assert_eq!(stats.played, 2);
+        assert_eq!(stats.distribution, vec![1, 1, 0, 0, 0, 0]);
+        assert_eq!(stats.average_remaining, vec![1.0]);
+    }
+}
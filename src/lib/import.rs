@@ -0,0 +1,106 @@
+use crate::{letters_to_string, parse_row, Color, Error, Letter};
+use crate::game::WORD_LENGTH;
+
+fn tile_color(ch: char) -> Option<Color> {
+    match ch {
+        '🟨' => Some(Color::Yellow),
+        '⬜' => Some(Color::White),
+        '⬛' => Some(Color::Gray),
+        _ => None,
+    }
+}
+
+/// Colors of a grid line like `⬛🟨⬛⬛🟨`, the variation selectors are skipped.
+fn parse_grid_line(line: &str) -> Option<Vec<Color>> {
+    let colors: Option<Vec<Color>> = line.chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '\u{fe0f}')
+        .map(tile_color)
+        .collect();
+    colors.filter(|colors| !colors.is_empty())
+}
+
+/// The colors as a grid line of the shared result, e.g. `⬛🟨⬛⬛🟨`.
+pub fn grid_line(colors: &[Color]) -> String {
+    colors.iter().map(|color| match color {
+        Color::Yellow => '🟨',
+        Color::White => '⬜',
+        Color::Gray => '⬛',
+    }).collect()
+}
+
+fn is_cyrillic(ch: char) -> bool {
+    ('а'..='я').contains(&ch) || ch == 'ё'
+}
+
+/// Reads a played game pasted from the clipboard: the rows in the `=`/`?` notation,
+/// or the words along with the shared emoji grid. The rows are returned in the notation.
+pub fn import_game(text: &str) -> Result<Vec<String>, Error> {
+    let mut grid: Vec<Vec<Color>> = Vec::new();
+    let mut words: Vec<String> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.starts_with("5 букв") {
+            continue;
+        }
+        if let Some(colors) = parse_grid_line(line) {
+            grid.push(colors);
+            continue;
+        }
+        // Numbering, punctuation and the like are skipped.
+        words.extend(line.split(|ch: char| ch.is_whitespace() || ch == ',')
+            .map(str::to_lowercase)
+            .filter(|token| token.chars().any(is_cyrillic)));
+    }
+
+    if words.is_empty() {
+        return Err(Error::Import(String::from("No rows found")));
+    }
+    if grid.is_empty() {
+        return words.iter().map(|word| parse_row(word).map(|row| letters_to_string(&row))).collect();
+    }
+    rows_from_colors(&words, &grid)
+}
+
+/// Pairs the typed words with the colors of their tiles, the rows are returned in the notation.
+pub fn rows_from_colors(words: &[String], grid: &[Vec<Color>]) -> Result<Vec<String>, Error> {
+    if grid.len() != words.len() {
+        return Err(Error::Import(format!("{} words, but {} grid lines", words.len(), grid.len())));
+    }
+    words.iter().zip(grid.iter()).map(|(word, colors)| {
+        let letters: Vec<char> = word.to_lowercase().chars().filter(|ch| is_cyrillic(*ch)).collect();
+        if letters.len() != WORD_LENGTH || colors.len() != WORD_LENGTH {
+            return Err(Error::WrongRow { row: word.clone(), letters: letters.len().min(colors.len()) });
+        }
+        let row: Vec<Letter> = letters.iter().zip(colors.iter()).map(|(ch, color)| Letter::new(*ch, *color)).collect();
+        Ok(letters_to_string(&row))
+    }).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_import_notation() {
+        assert_eq!(import_game("лента\nс=удь=я\n").unwrap(), vec!["лента", "с=удь=я"]);
+        assert_eq!(import_game("1. Лента, ?игрок").unwrap(), vec!["лента", "?игрок"]);
+    }
+
+    #[test]
+    fn test_import_grid() {
+        let text = "лента судья мумия\n5 букв 3/6\n⬛⬛⬛⬛⬛\n⬛🟨⬛⬛🟨\n🟨🟨🟨🟨🟨\n";
+        assert_eq!(import_game(text).unwrap(), vec!["лента", "с=удь=я", "=м=у=м=и=я"]);
+    }
+
+    #[test]
+    fn test_import_grid_with_selectors() {
+        assert_eq!(import_game("игрок\n⬜\u{fe0f}⬛⬛⬛⬛").unwrap(), vec!["?игрок"]);
+    }
+
+    #[test]
+    fn test_import_errors() {
+        assert_eq!(import_game("").unwrap_err().code(), "wrong_import");
+        assert_eq!(import_game("лента судья\n⬛⬛⬛⬛⬛").unwrap_err().code(), "wrong_import");
+        assert_eq!(import_game("лент\n⬛⬛⬛⬛⬛").unwrap_err().code(), "wrong_row");
+        assert_eq!(import_game("лент").unwrap_err().code(), "wrong_row");
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{parse_rows, Error, Letter, Stat};
+
+pub const ALPHABET: &str = "абвгдеёжзийклмнопрстуфхцчшщъыьэюя";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LetterStatus {
+    /// The letter is known at some position.
+    Correct,
+    /// The letter is in the answer, the position is unknown.
+    Present,
+    Absent,
+    Unknown,
+}
+
+/// What is known about every letter of the alphabet.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KeyboardState {
+    pub letters: BTreeMap<char, LetterStatus>,
+    /// Letters which may still be at the position, in the alphabet order.
+    pub positions: Vec<Vec<char>>,
+}
+
+impl KeyboardState {
+    // The rows are not validated: the game may color a repeated letter
+    // both gray and yellow, the present color wins then.
+    fn from_stat(stat: &Stat) -> KeyboardState {
+        let present: Vec<char> = stat.white_letters.values().flatten().copied().collect();
+        let letters = ALPHABET.chars().map(|ch| {
+            let status = if stat.yellow_letters.values().any(|l| *l == ch) {
+                LetterStatus::Correct
+            } else if present.contains(&ch) {
+                LetterStatus::Present
+            } else if stat.gray_letters.contains(&ch) {
+                LetterStatus::Absent
+            } else {
+                LetterStatus::Unknown
+            };
+            (ch, status)
+        }).collect::<BTreeMap<_, _>>();
+
+        let positions = (0..5).map(|idx| match stat.yellow_letters.get(&idx) {
+            Some(ch) => vec![*ch],
+            None => ALPHABET.chars()
+                .filter(|ch| letters[ch] != LetterStatus::Absent)
+                .filter(|ch| !stat.white_letters.get(&idx).is_some_and(|white| white.contains(ch)))
+                .collect(),
+        }).collect();
+
+        KeyboardState { letters, positions }
+    }
+
+    pub fn from_rows(rows: &[Vec<Letter>]) -> KeyboardState {
+        let mut stat = Stat::default();
+        rows.iter().for_each(|row| stat.add_row(row));
+        KeyboardState::from_stat(&stat)
+    }
+
+    pub fn status(&self, letter: char) -> LetterStatus {
+        self.letters.get(&letter).copied().unwrap_or(LetterStatus::Unknown)
+    }
+}
+
+/// Same as `KeyboardState::from_rows` for the rows in the `=`/`?` notation.
+pub fn keyboard_state(rows: &[String]) -> Result<KeyboardState, Error> {
+    Ok(KeyboardState::from_rows(&parse_rows(rows)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strings;
+
+    #[test]
+    fn test_letter_statuses() {
+        let state = keyboard_state(&strings(&["лента", "с=удь=я", "?игрок"])).unwrap();
+        assert_eq!(state.letters.len(), 33);
+        assert_eq!(state.status('у'), LetterStatus::Correct);
+        assert_eq!(state.status('и'), LetterStatus::Present);
+        assert_eq!(state.status('л'), LetterStatus::Absent);
+        assert_eq!(state.status('м'), LetterStatus::Unknown);
+    }
+
+    #[test]
+    fn test_positions() {
+        let state = keyboard_state(&strings(&["лента", "с=удь=я", "?игрок"])).unwrap();
+        assert_eq!(state.positions[1], vec!['у']);
+        assert!(!state.positions[0].contains(&'и'));
+        assert!(state.positions[0].contains(&'м'));
+        assert!(!state.positions[0].contains(&'л'));
+        assert!(state.positions[2].contains(&'и'));
+    }
+
+    #[test]
+    fn test_repeated_letter() {
+        let state = keyboard_state(&strings(&["м=ама=м"])).unwrap();
+        assert_eq!(state.status('м'), LetterStatus::Correct);
+        assert_eq!(state.status('а'), LetterStatus::Correct);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let state = keyboard_state(&strings(&["с=удь=я"])).unwrap();
+        let value = serde_json::to_value(&state).unwrap();
+        assert_eq!(value["letters"]["у"], "correct");
+        assert_eq!(value["letters"]["с"], "absent");
+        assert_eq!(value["positions"][4], serde_json::json!(["я"]));
+    }
+
+    #[test]
+    fn test_wrong_row() {
+        assert_eq!(keyboard_state(&strings(&["лент"])).unwrap_err().code(), "wrong_row");
+    }
+}
@@ -1,23 +1,65 @@
 use std::{char, collections::{HashMap,HashSet}};
+#[cfg(feature = "os-rng")]
 use rand::{thread_rng, Rng};
+#[cfg(feature = "os-rng")]
 use rand::seq::SliceRandom;
 
+mod error;
+pub mod adversarial;
+pub mod analysis;
+pub mod bot;
+pub mod daily;
+pub mod explain;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod frequency;
+pub mod game;
+pub mod history;
+pub mod import;
+pub mod keyboard;
+pub mod multiboard;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod rank;
+pub mod screenshot;
+pub mod session;
+pub mod simulate;
+pub mod solve;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod wordlist;
 mod words;
 
-#[derive(Debug, PartialEq)] 
+pub use error::Error;
+
+#[derive(Debug, PartialEq, Clone, Copy)] 
 pub enum Color {
     Gray,  // Absent.
     White,  // Presented, but in different position.
     Yellow, // Guessed.
 }
 
-#[derive(Debug)] 
+#[derive(Debug, Clone)] 
 pub struct Letter {
     color: Color,
     letter: char,
 }
 
-#[derive(Debug)] 
+impl Letter {
+    pub fn new(letter: char, color: Color) -> Letter {
+        Letter { color, letter }
+    }
+
+    pub fn letter(&self) -> char {
+        self.letter
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+}
+
+#[derive(Debug, Default, Clone)] 
 struct Stat {
     yellow_letters: HashMap<usize, char>,
     white_letters: HashMap<usize, Vec<char>>,
@@ -26,6 +68,28 @@ struct Stat {
 
 
 impl Stat {
+    /// A gray letter which is colored elsewhere in the same row only says that the answer
+    /// has no more of it, such letters are not counted as absent.
+    #[allow(clippy::map_entry, clippy::option_map_unit_fn)]
+    fn add_row(&mut self, word: &[Letter]) {
+        let colored: HashSet<char> = word.iter().filter(|l| l.color != Color::Gray).map(|l| l.letter).collect();
+        for (idx, letter) in word.iter().enumerate() {
+            match letter.color {
+                Color::Yellow => {self.yellow_letters.entry(idx).or_insert(letter.letter);},
+                Color::White => {
+                    if self.white_letters.contains_key(&idx) {
+                        self.white_letters.get_mut(&idx).map(|val| val.push(letter.letter));
+                    } else {
+                        self.white_letters.insert(idx, vec![letter.letter]);
+                    };
+                },
+                Color::Gray if colored.contains(&letter.letter) => (),
+                Color::Gray => {self.gray_letters.insert(letter.letter);},
+            }
+        }
+    }
+
+    #[allow(clippy::len_zero, clippy::needless_borrow)]
     fn validate(&self) -> (bool, Vec<String>) {
         let mut wlset = HashSet::<char>::new();
         let mut errors = Vec::<String>::new();
@@ -59,25 +123,28 @@ impl Stat {
         !self.yellow_letters.iter().any(|(idx, ch)| *ch != (*dict_word).chars().nth(*idx).unwrap())
     }
 
+    #[allow(clippy::map_clone)]
     fn has_white_in_place(self: &Stat, dict_word: &str) -> bool {
-        if !dict_word.chars().enumerate().any(|(idx, ch)| {
-            !self.yellow_letters.contains_key(&idx) || (self.white_letters.contains_key(&idx) && self.white_letters[&idx].contains(&ch))
-        }) {
-            return false;
-        }
         let unique_word_chars = HashSet::from_iter(dict_word.chars());
         let wlset: HashSet<char> = self.white_letters.values().flatten().map(|ch| *ch).collect();
         (wlset.difference(&unique_word_chars)).count() == 0
     }
 
+    #[allow(clippy::needless_borrow)]
     fn is_matched(self: &Stat, dict_word: &str) -> bool{
         !(self.has_gray_letters(&dict_word) || !self.has_yellow_in_place(&dict_word) || !self.has_white_in_place(&dict_word))
     }
 
 }
 
-fn find_matches(stats: Stat) -> Vec<&'static str> {
-    words::WORDLIST.iter().filter(|word| stats.is_matched(word)).map(|w| *w).collect()
+/// Words which could be the answer after the rows: the stat drops most of the dictionary
+/// cheaply, the rest is scored the way the game does, so the repeated letters count right.
+#[allow(clippy::map_clone)]
+fn find_matches(stats: &Stat, rows: &[Vec<Letter>]) -> Vec<&'static str> {
+    words::WORDLIST.iter()
+        .filter(|word| stats.is_matched(word) && rows.iter().all(|row| game::is_consistent(row, word)))
+        .map(|w| *w)
+        .collect()
 }
 
 pub fn string_to_letters(word: &String) -> Vec<Letter> {
@@ -85,6 +152,25 @@ pub fn string_to_letters(word: &String) -> Vec<Letter> {
     if chars.len() > 10 || chars.len() < 5 {
         panic!("String of length from 5 to 10 chars is expected, but {} given: {}", chars.len(), word);
     }
+    chars_to_letters(&chars)
+}
+
+/// Same as `string_to_letters`, but a wrong row is an error instead of a panic.
+pub fn parse_row(word: &str) -> Result<Vec<Letter>, Error> {
+    let chars = word.chars().collect::<Vec<char>>();
+    let letters = if chars.is_empty() { Vec::new() } else { chars_to_letters(&chars) };
+    if letters.len() != 5 {
+        return Err(Error::WrongRow { row: String::from(word), letters: letters.len() });
+    }
+    Ok(letters)
+}
+
+pub fn parse_rows(rows: &[String]) -> Result<Vec<Vec<Letter>>, Error> {
+    rows.iter().map(|row| parse_row(row)).collect()
+}
+
+#[allow(clippy::get_first, clippy::unused_enumerate_index)]
+fn chars_to_letters(chars: &[char]) -> Vec<Letter> {
     let mut res: Vec<Letter> = Vec::new();
     let first = chars.get(0).unwrap();
     if first.is_alphabetic() {
@@ -92,7 +178,7 @@ pub fn string_to_letters(word: &String) -> Vec<Letter> {
     }
 
     let mut prev = first;
-    for (idx, ch) in chars.iter().skip(1).enumerate() {
+    for (_idx, ch) in chars.iter().skip(1).enumerate() {
         if *ch != '^' || *ch != '?' || *ch != '=' {
             if ch.is_alphabetic() && (prev.is_alphabetic() || *prev == '^') {
                 res.push(Letter { color: Color::Gray, letter: *ch });
@@ -107,71 +193,86 @@ pub fn string_to_letters(word: &String) -> Vec<Letter> {
     res
 }
 
+#[allow(clippy::needless_borrow)]
 pub fn strings_to_words(strings: Vec<String>) -> Vec<Vec<Letter>> {
     strings.iter().map(|string| string_to_letters(&string)).collect::<Vec<_>>()
 }
 
-fn get_letters_stat(words: Vec<Vec<Letter>>) -> Stat {
-    let mut yellow_letters = HashMap::new();
-    let mut gray_letters = HashSet::new();
-    let mut white_letters: HashMap<usize, Vec<char>> = HashMap::new();
-    for word in words {
-        for (idx, letter) in word.iter().enumerate() {
-            match letter.color {
-                Color::Yellow => {yellow_letters.entry(idx).or_insert(letter.letter);},
-                Color::White => {
-                    if white_letters.contains_key(&idx) {
-                        white_letters.get_mut(&idx).map(|val| val.push(letter.letter));
-                    } else {
-                        white_letters.insert(idx, vec![letter.letter]);
-                    };
-                },
-                Color::Gray => {gray_letters.insert(letter.letter);},
-            }
+/// Writes the row back in the `=`/`?` notation.
+pub fn letters_to_string(letters: &[Letter]) -> String {
+    let mut res = String::new();
+    for letter in letters {
+        match letter.color {
+            Color::Yellow => res.push('='),
+            Color::White => res.push('?'),
+            Color::Gray => (),
         }
+        res.push(letter.letter);
     }
+    res
+}
 
-    let stats = Stat {
-        yellow_letters: yellow_letters,
-        gray_letters: gray_letters,
-        white_letters: white_letters,
-    };
+fn stat_from_rows(words: &[Vec<Letter>]) -> Result<Stat, Error> {
+    let mut stats = Stat::default();
+    for word in words {
+        stats.add_row(word);
+    }
 
     let (success, errors) = stats.validate();
     if !success {
-        panic!("Errors: {:?}", errors);
+        return Err(Error::ConflictingRows(errors));
     };
-    stats
+    Ok(stats)
 }
 
+fn get_letters_stat(words: Vec<Vec<Letter>>) -> Stat {
+    match stat_from_rows(&words) {
+        Ok(stats) => stats,
+        Err(Error::ConflictingRows(errors)) => panic!("Errors: {:?}", errors),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[allow(clippy::len_zero)]
 pub fn guess_word(words: Vec<Vec<Letter>>) -> Vec<&'static str> {
     if words.len() == 0 {
         suggest_words()
     } else {
-        let stats = get_letters_stat(words);
-        find_matches(stats)
+        let stats = get_letters_stat(words.clone());
+        find_matches(&stats, &words)
     }
 }
 
 pub fn suggest_words() -> Vec<&'static str> {
-    let words = vec![
-        vec!["смазь", "флейц", "будяк", "выгон", "причт"],
-        vec!["плица", "гнусь", "вздор", "тюфяк", "мышей"],
-        vec!["дымка", "овсец", "хлябь", "югрич", "шпунт"],
-        vec!["взрыд", "аншеф", "пигус", "хлябь", "тючок"],
-        vec!["шутка", "мысль", "пожня", "югрич", "въезд"],
-        vec!["чужая", "кофий", "взбег", "шпынь", "хлюст"],
-        vec!["фрукт", "мышца", "почин", "хлябь", "въезд"],
-        vec!["удэге", "барий", "взлом", "тюфяк", "шпынь"],
-        vec!["сдвиг", "щерба", "экзот", "шпынь", "муляж"],
-        vec!["жучок", "знать", "мюрид", "флейц", "вспых"],
+    let words = [
+        ["смазь", "флейц", "будяк", "выгон", "причт"],
+        ["плица", "гнусь", "вздор", "тюфяк", "мышей"],
+        ["дымка", "овсец", "хлябь", "югрич", "шпунт"],
+        ["взрыд", "аншеф", "пигус", "хлябь", "тючок"],
+        ["шутка", "мысль", "пожня", "югрич", "въезд"],
+        ["чужая", "кофий", "взбег", "шпынь", "хлюст"],
+        ["фрукт", "мышца", "почин", "хлябь", "въезд"],
+        ["удэге", "барий", "взлом", "тюфяк", "шпынь"],
+        ["сдвиг", "щерба", "экзот", "шпынь", "муляж"],
+        ["жучок", "знать", "мюрид", "флейц", "вспых"],
     ];
-    let n = thread_rng().gen_range(0..words.len());
-    words[n].clone()
+    words[random_index(words.len())].to_vec()
+}
+
+#[cfg(feature = "os-rng")]
+fn random_index(len: usize) -> usize {
+    thread_rng().gen_range(0..len)
 }
 
+/// Without the OS entropy the first one is always taken.
+#[cfg(not(feature = "os-rng"))]
+fn random_index(_len: usize) -> usize {
+    0
+}
+
+#[cfg(feature = "os-rng")]
 pub fn find_optimal_words() -> Vec<&'static str> {
-    let mut words_copy = words::WORDLIST.clone();
+    let mut words_copy = words::WORDLIST.to_vec();
     words_copy.shuffle(&mut thread_rng());
     let mut matches = Vec::new();
     let mut tried_chars: HashSet<char> = HashSet::new();
@@ -190,15 +291,24 @@ pub fn find_optimal_words() -> Vec<&'static str> {
     matches
 }
 
+#[cfg(feature = "os-rng")]
+#[allow(clippy::useless_conversion)]
 pub fn get_suggestions() -> Vec<Vec<&'static str>> {
     (1..=1000).into_iter().map(|_| find_optimal_words()).filter(|suggestions| suggestions.len() > 4).collect()
 }
 
+/// Owned strings for the tests, like the rows in the `=`/`?` notation.
+#[cfg(test)]
+pub(crate) fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| String::from(*value)).collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_has_gray_letters() {
         let stats = Stat{
             gray_letters: HashSet::from(['п', 'р']),
@@ -210,6 +320,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_has_white_in_place() {
         let stats = Stat{
             gray_letters: HashSet::new(),
@@ -220,6 +331,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_has_white_in_place_with_wrong_stats() {
         let stats = Stat{
             gray_letters: HashSet::new(),
@@ -256,6 +368,20 @@ mod test {
         assert_eq!(res[1].letter, 'e');
     }
 
+    #[test]
+    fn test_parse_row() {
+        assert_eq!(parse_row("с=удь=я").unwrap().len(), 5);
+        assert_eq!(parse_row("").unwrap_err(), Error::WrongRow { row: String::from(""), letters: 0 });
+        assert_eq!(parse_row("лен=т").unwrap_err(), Error::WrongRow { row: String::from("лен=т"), letters: 4 });
+        assert_eq!(parse_row("ghyeglylwowo").unwrap_err().code(), "wrong_row");
+    }
+
+    #[test]
+    fn test_letters_to_string() {
+        assert_eq!(letters_to_string(&string_to_letters(&String::from("с=удь=я"))), "с=удь=я");
+        assert_eq!(letters_to_string(&string_to_letters(&String::from("?игрок"))), "?игрок");
+    }
+
     #[test]
     fn test_guess_word(){
         let words = strings_to_words(vec![
@@ -269,6 +395,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_validate_white_letters() {
         let stats = Stat{
             gray_letters: HashSet::from(['c', 'h']),
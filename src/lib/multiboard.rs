@@ -0,0 +1,183 @@
+use crate::{find_matches, Error, Letter, Stat};
+use crate::game::{is_row_solved, row_word};
+use crate::rank::rank_dictionary;
+use crate::solve::Candidate;
+
+#[derive(Debug, Clone, Default)]
+pub struct Board {
+    stat: Stat,
+    rows: Vec<Vec<Letter>>,
+    solved: Option<String>,
+}
+
+impl Board {
+    pub fn rows(&self) -> &[Vec<Letter>] {
+        &self.rows
+    }
+
+    /// The answer of the board if it is already guessed.
+    pub fn solved(&self) -> Option<&str> {
+        self.solved.as_deref()
+    }
+
+    pub fn candidates(&self) -> Vec<&'static str> {
+        match &self.solved {
+            Some(_) => Vec::new(),
+            None => find_matches(&self.stat, &self.rows),
+        }
+    }
+
+    fn add_row(&mut self, row: Vec<Letter>) -> Result<(), Vec<String>> {
+        let mut stat = self.stat.clone();
+        stat.add_row(&row);
+        let (success, errors) = stat.validate();
+        if !success {
+            return Err(errors);
+        }
+        if is_row_solved(&row) {
+            self.solved = Some(row_word(&row));
+        }
+        self.stat = stat;
+        self.rows.push(row);
+        Ok(())
+    }
+}
+
+/// Several boards sharing the same guesses (Dordle, Quordle).
+#[derive(Debug, Clone)]
+pub struct MultiBoard {
+    boards: Vec<Board>,
+}
+
+impl MultiBoard {
+    pub fn new(count: usize) -> MultiBoard {
+        MultiBoard { boards: vec![Board::default(); count] }
+    }
+
+    /// Builds the boards from the rows entered for each of them.
+    /// A solved board has no rows after the one it was guessed with.
+    pub fn from_boards(rows: Vec<Vec<Vec<Letter>>>) -> Result<MultiBoard, Error> {
+        let mut multiboard = MultiBoard::new(rows.len());
+        let mut rows: Vec<_> = rows.into_iter().map(|rows| rows.into_iter()).collect();
+        loop {
+            let feedback: Vec<Vec<Letter>> = rows.iter_mut().filter_map(|rows| rows.next()).collect();
+            if feedback.is_empty() {
+                return Ok(multiboard);
+            }
+            multiboard.add_row(feedback)?;
+        }
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.boards.iter().all(|b| b.solved.is_some())
+    }
+
+    /// Applies the feedback on the same guess to every unsolved board, in order.
+    pub fn add_row(&mut self, feedback: Vec<Vec<Letter>>) -> Result<(), Error> {
+        let unsolved = self.boards.iter().filter(|b| b.solved.is_none()).count();
+        if feedback.len() != unsolved {
+            let error = format!("Feedback for {} unsolved boards is expected, but {} given", unsolved, feedback.len());
+            return Err(Error::ConflictingRows(vec![error]));
+        }
+        let mut words: Vec<String> = feedback.iter().map(|row| row_word(row)).collect();
+        words.dedup();
+        if words.len() > 1 {
+            let error = format!("The same word is expected on every board, but {} given", words.join(", "));
+            return Err(Error::ConflictingRows(vec![error]));
+        }
+
+        let mut boards = self.boards.clone();
+        let mut errors = Vec::new();
+        let unsolved_boards = boards.iter_mut().enumerate().filter(|(_, b)| b.solved.is_none());
+        for ((idx, board), row) in unsolved_boards.zip(feedback) {
+            if let Err(board_errors) = board.add_row(row) {
+                errors.extend(board_errors.into_iter().map(|e| format!("Board {}: {}", idx + 1, e)));
+            }
+        }
+        if !errors.is_empty() {
+            return Err(Error::ConflictingRows(errors));
+        }
+        self.boards = boards;
+        Ok(())
+    }
+
+    pub fn candidates(&self) -> Vec<Vec<&'static str>> {
+        self.boards.iter().map(|b| b.candidates()).collect()
+    }
+
+    /// Guesses giving the most information summed over the unsolved boards.
+    pub fn recommend(&self, count: usize) -> Vec<Candidate> {
+        let candidates: Vec<Vec<&str>> = self.candidates().into_iter().filter(|c| !c.is_empty()).collect();
+        let mut ranked = rank_dictionary(&candidates);
+        ranked.truncate(count);
+        ranked.into_iter().map(Candidate::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{string_to_letters, strings_to_words};
+
+    fn rows(strings: &[&str]) -> Vec<Vec<Letter>> {
+        strings_to_words(strings.iter().map(|s| String::from(*s)).collect())
+    }
+
+    #[test]
+    fn test_boards_keep_own_candidates() {
+        let multiboard = MultiBoard::from_boards(vec![
+            rows(&["лента", "с=удь=я", "?игрок"]),
+            rows(&["л?ента", "судья", "игрок"]),
+        ]).unwrap();
+        let candidates = multiboard.candidates();
+        assert_eq!(candidates[0], vec!["мумия"]);
+        assert_eq!(candidates[1], vec!["мышей"]);
+    }
+
+    #[test]
+    fn test_solved_board_drops_out() {
+        let mut multiboard = MultiBoard::new(2);
+        multiboard.add_row(rows(&["=м=у=м=и=я", "мумия"])).unwrap();
+        assert_eq!(multiboard.boards()[0].solved(), Some("мумия"));
+        assert!(!multiboard.is_solved());
+        assert!(multiboard.candidates()[0].is_empty());
+
+        assert!(multiboard.add_row(rows(&["игрок", "игрок"])).is_err());
+        multiboard.add_row(rows(&["игрок"])).unwrap();
+        assert_eq!(multiboard.boards()[1].rows().len(), 2);
+    }
+
+    #[test]
+    fn test_same_word_on_every_board() {
+        let mut multiboard = MultiBoard::new(2);
+        let errors = multiboard.add_row(rows(&["лента", "игрок"])).unwrap_err();
+        let expected = vec![String::from("The same word is expected on every board, but лента, игрок given")];
+        assert_eq!(errors, Error::ConflictingRows(expected));
+        assert_eq!(multiboard.boards()[0].rows().len(), 0);
+    }
+
+    #[test]
+    fn test_invalid_row_is_not_applied() {
+        let mut multiboard = MultiBoard::new(2);
+        multiboard.add_row(vec![string_to_letters(&String::from("лента")), string_to_letters(&String::from("лента"))]).unwrap();
+        let errors = multiboard.add_row(vec![string_to_letters(&String::from("=лента")), string_to_letters(&String::from("лента"))]).unwrap_err();
+        assert_eq!(errors.code(), "conflicting_rows");
+        assert_eq!(errors.to_string(), "Board 1: Yellow letter `л` has been found in grays");
+        assert_eq!(multiboard.boards()[1].rows().len(), 1);
+    }
+
+    #[test]
+    fn test_recommend() {
+        let multiboard = MultiBoard::from_boards(vec![
+            rows(&["лента", "с=удь=я"]),
+            rows(&["л?ента", "судья"]),
+        ]).unwrap();
+        let recommended = multiboard.recommend(3);
+        assert_eq!(recommended.len(), 3);
+        assert!(recommended[0].score >= recommended[2].score);
+    }
+}
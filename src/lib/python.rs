@@ -0,0 +1,156 @@
+// Python module for the notebooks, built with the `python` feature:
+// `maturin develop` in the repository root makes `import tinkoff_guess_game` work.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{parse_rows, words, Color, Letter};
+use crate::game::{is_consistent, score as score_colors, MAX_ATTEMPTS, WORD_LENGTH};
+use crate::rank::{entropy, rank_guesses, DEFAULT_RANK_COUNT};
+
+fn value_error(err: impl ToString) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::Yellow => "yellow",
+        Color::White => "white",
+        Color::Gray => "gray",
+    }
+}
+
+fn check_word(word: &str) -> PyResult<String> {
+    let word = word.trim().to_lowercase();
+    match word.chars().count() {
+        WORD_LENGTH => Ok(word),
+        len => Err(value_error(format!("Word of {} letters is expected, but {} given: {}", WORD_LENGTH, len, word))),
+    }
+}
+
+/// Colors of the guess for the answer: "yellow" is in place, "white" is elsewhere, "gray" is absent.
+#[pyfunction]
+fn score(guess: &str, answer: &str) -> PyResult<Vec<&'static str>> {
+    Ok(score_colors(&check_word(guess)?, &check_word(answer)?).into_iter().map(color_name).collect())
+}
+
+/// The row in the `=`/`?` notation as (letter, color) pairs.
+#[pyfunction]
+fn parse_row(row: &str) -> PyResult<Vec<(char, &'static str)>> {
+    let row = crate::parse_row(row).map_err(value_error)?;
+    Ok(row.iter().map(|letter| (letter.letter(), color_name(letter.color()))).collect())
+}
+
+/// Words to play with, the built-in dictionary unless other words are given.
+#[pyclass(module = "tinkoff_guess_game")]
+struct Dictionary {
+    words: Vec<String>,
+}
+
+impl Dictionary {
+    fn matching(&self, rows: &[Vec<Letter>]) -> Vec<&str> {
+        self.words.iter()
+            .filter(|word| rows.iter().all(|row| is_consistent(row, word)))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+#[pymethods]
+impl Dictionary {
+    #[new]
+    #[pyo3(signature = (words=None))]
+    fn new(words: Option<Vec<String>>) -> PyResult<Dictionary> {
+        let words = match words {
+            Some(words) => words.iter().map(|word| check_word(word)).collect::<PyResult<_>>()?,
+            None => words::WORDLIST.iter().map(|word| String::from(*word)).collect(),
+        };
+        Ok(Dictionary { words })
+    }
+
+    #[getter]
+    fn words(&self) -> Vec<String> {
+        self.words.clone()
+    }
+
+    fn __len__(&self) -> usize {
+        self.words.len()
+    }
+
+    fn __contains__(&self, word: &str) -> bool {
+        self.words.iter().any(|w| w == word)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Dictionary({} words)", self.words.len())
+    }
+
+    /// Words which may be the answer after the rows in the `=`/`?` notation.
+    fn filter(&self, rows: Vec<String>) -> PyResult<Vec<String>> {
+        let rows = parse_rows(&rows).map_err(value_error)?;
+        Ok(self.matching(&rows).into_iter().map(String::from).collect())
+    }
+
+    /// Expected information of the guess in bits about the words left after the rows.
+    #[pyo3(signature = (guess, rows=Vec::new()))]
+    fn entropy(&self, guess: &str, rows: Vec<String>) -> PyResult<f64> {
+        let rows = parse_rows(&rows).map_err(value_error)?;
+        Ok(entropy(&check_word(guess)?, &self.matching(&rows)))
+    }
+
+    /// The best guesses after the rows with their information in bits, best first.
+    /// The guesses are taken from the dictionary unless given.
+    #[pyo3(signature = (rows=Vec::new(), count=DEFAULT_RANK_COUNT, guesses=None))]
+    fn rank(&self, rows: Vec<String>, count: usize, guesses: Option<Vec<String>>) -> PyResult<Vec<(String, f64)>> {
+        let rows = parse_rows(&rows).map_err(value_error)?;
+        let guesses = match guesses {
+            Some(guesses) => guesses.iter().map(|guess| check_word(guess)).collect::<PyResult<Vec<_>>>()?,
+            None => self.words.clone(),
+        };
+        let guesses: Vec<&str> = guesses.iter().map(String::as_str).collect();
+        let mut ranked = rank_guesses(&guesses, &[self.matching(&rows)]);
+        ranked.truncate(count);
+        Ok(ranked.into_iter().map(|(word, bits)| (String::from(word), bits)).collect())
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "tinkoff_guess_game")]
+fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<Dictionary>()?;
+    module.add_function(wrap_pyfunction!(score, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_row, module)?)?;
+    module.add("WORDLIST", words::WORDLIST.to_vec())?;
+    module.add("MAX_ATTEMPTS", MAX_ATTEMPTS)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strings;
+
+    #[test]
+    fn test_score() {
+        assert_eq!(score("мумия", "мамка").unwrap(), vec!["yellow", "gray", "yellow", "gray", "gray"]);
+        assert!(score("мумиями", "мамка").is_err());
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let dictionary = Dictionary::new(None).unwrap();
+        assert_eq!(dictionary.__len__(), words::WORDLIST.len());
+        assert_eq!(dictionary.filter(strings(&["лента", "с=удь=я", "?игрок"])).unwrap(), vec!["мумия"]);
+        assert!(dictionary.filter(strings(&["лент"])).is_err());
+    }
+
+    #[test]
+    fn test_custom_dictionary() {
+        let dictionary = Dictionary::new(Some(strings(&["Мумия", "мамка", "игрок"]))).unwrap();
+        assert!(dictionary.__contains__("мумия"));
+        assert!(dictionary.entropy("мумия", Vec::new()).unwrap() > 1.0);
+        let ranked = dictionary.rank(Vec::new(), 2, None).unwrap();
+        assert_eq!(ranked.len(), 2);
+        assert!(Dictionary::new(Some(strings(&["мумиями"]))).is_err());
+    }
+}
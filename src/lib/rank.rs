@@ -0,0 +1,121 @@
+use crate::words;
+
+const PATTERNS: usize = 243;
+
+fn to_chars(word: &str) -> [char; 5] {
+    let mut chars = ['\0'; 5];
+    for (idx, ch) in word.chars().take(5).enumerate() {
+        chars[idx] = ch;
+    }
+    chars
+}
+
+// Same as `game::pattern_code(&game::score(..))` without allocations,
+// ranking calls it for every pair of the dictionary words.
+fn fast_code(guess: &[char; 5], answer: &[char; 5]) -> usize {
+    let mut used = [false; 5];
+    let mut colors = [0; 5];
+    for idx in 0..5 {
+        if guess[idx] == answer[idx] {
+            colors[idx] = 2;
+            used[idx] = true;
+        }
+    }
+    for idx in 0..5 {
+        if colors[idx] != 0 {
+            continue;
+        }
+        if let Some(pos) = (0..5).find(|pos| !used[*pos] && guess[idx] == answer[*pos]) {
+            colors[idx] = 1;
+            used[pos] = true;
+        }
+    }
+    colors.iter().fold(0, |code, color| code * 3 + color)
+}
+
+fn entropy_of(guess: &[char; 5], candidates: &[[char; 5]]) -> f64 {
+    if candidates.is_empty() {
+        return 0.0;
+    }
+    let mut counts = [0usize; PATTERNS];
+    for candidate in candidates {
+        counts[fast_code(guess, candidate)] += 1;
+    }
+    let total = candidates.len() as f64;
+    counts.iter().filter(|n| **n > 0).map(|n| {
+        let p = *n as f64 / total;
+        p * (1.0 / p).log2()
+    }).sum()
+}
+
+/// Expected information (in bits) the guess gives about the candidates.
+pub fn entropy(guess: &str, candidates: &[&str]) -> f64 {
+    let candidates: Vec<_> = candidates.iter().map(|c| to_chars(c)).collect();
+    entropy_of(&to_chars(guess), &candidates)
+}
+
+/// Rates every guess by the information it gives summed over the candidate
+/// sets, best first. Guesses which may be the answer win the ties.
+pub fn rank_guesses<'a>(guesses: &[&'a str], candidate_sets: &[Vec<&str>]) -> Vec<(&'a str, f64)> {
+    let letters: Vec<[char; 5]> = guesses.iter().map(|guess| to_chars(guess)).collect();
+    rank_letters(guesses, &letters, candidate_sets)
+}
+
+/// Same as `rank_guesses` with the letters of the guesses already split.
+fn rank_letters<'a>(guesses: &[&'a str], letters: &[[char; 5]], candidate_sets: &[Vec<&str>]) -> Vec<(&'a str, f64)> {
+    let sets: Vec<Vec<[char; 5]>> = candidate_sets.iter()
+        .map(|set| set.iter().map(|c| to_chars(c)).collect())
+        .collect();
+    let mut ranked: Vec<(&'a str, f64, bool)> = guesses.iter().zip(letters).map(|(guess, chars)| {
+        let bits = sets.iter().map(|set| entropy_of(chars, set)).sum();
+        let possible = candidate_sets.iter().any(|set| set.contains(guess));
+        (*guess, bits, possible)
+    }).collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.2.cmp(&a.2)));
+    ranked.into_iter().map(|(guess, bits, _)| (guess, bits)).collect()
+}
+
+/// `rank_guesses` for the whole dictionary, its letters are split at build time.
+pub fn rank_dictionary(candidate_sets: &[Vec<&str>]) -> Vec<(&'static str, f64)> {
+    rank_letters(words::WORDLIST, words::LETTERS, candidate_sets)
+}
+
+/// Number of the best guesses shown when the caller doesn't ask for another one.
+pub const DEFAULT_RANK_COUNT: usize = 10;
+
+/// Best guesses from the whole dictionary for a single candidate set.
+pub fn best_guesses(candidates: &[&str], count: usize) -> Vec<(&'static str, f64)> {
+    let mut ranked = rank_dictionary(&[candidates.to_vec()]);
+    ranked.truncate(count);
+    ranked
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::game::{pattern_code, score};
+
+    #[test]
+    fn test_fast_code_matches_score() {
+        for (guess, answer) in [("мумия", "мамка"), ("мумия", "смазь"), ("лента", "мумия"), ("игрок", "мумия")] {
+            assert_eq!(fast_code(&to_chars(guess), &to_chars(answer)), pattern_code(&score(guess, answer)) as usize);
+        }
+    }
+
+    #[test]
+    fn test_entropy() {
+        assert_eq!(entropy("лента", &["мумия"]), 0.0);
+        assert_eq!(entropy("лента", &[]), 0.0);
+        // Two candidates told apart by the guess give a whole bit.
+        assert_eq!(entropy("мумия", &["мумия", "лента"]), 1.0);
+        assert_eq!(entropy("бедро", &["мумия", "мамка"]), 0.0);
+    }
+
+    #[test]
+    fn test_rank_guesses() {
+        let candidates = vec![vec!["мумия", "мамка"]];
+        let ranked = rank_guesses(&["игрок", "мамка", "мумия"], &candidates);
+        assert_eq!(ranked.iter().map(|(w, _)| *w).collect::<Vec<_>>(), vec!["мамка", "мумия", "игрок"]);
+        assert_eq!(ranked[0].1, 1.0);
+    }
+}
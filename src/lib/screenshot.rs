@@ -0,0 +1,176 @@
+// Reads the tile colors from a screenshot of the banking app, no letters are recognized:
+// the words are typed by the user and paired with the colors by `rows_from_screenshot`.
+
+use std::collections::HashMap;
+
+use image::{Rgb, RgbImage};
+
+use crate::{Color, Error};
+use crate::game::WORD_LENGTH;
+use crate::import::rows_from_colors;
+
+/// Tile color of the pixel for the dark theme of the app. The background, the outlines
+/// of the empty tiles and the letters on the light tiles are too dark to count.
+fn pixel_color(Rgb([r, g, b]): Rgb<u8>) -> Option<Color> {
+    let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+    if min > 200 {
+        Some(Color::White)
+    } else if r > 180 && g > 140 && b < 120 {
+        Some(Color::Yellow)
+    } else if max - min < 24 && (80..=200).contains(&max) {
+        Some(Color::Gray)
+    } else {
+        None
+    }
+}
+
+/// Half-open ranges of the indexes where `is_on` holds.
+fn runs(len: u32, is_on: impl Fn(u32) -> bool) -> Vec<(u32, u32)> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for idx in 0..=len {
+        match (start, idx < len && is_on(idx)) {
+            (None, true) => start = Some(idx),
+            (Some(from), false) => {
+                runs.push((from, idx));
+                start = None;
+            },
+            _ => {},
+        }
+    }
+    runs
+}
+
+fn is_about(value: u32, expected: u32) -> bool {
+    value * 3 >= expected * 2 && value * 2 <= expected * 3
+}
+
+/// The most common tile color of the rectangle.
+fn dominant_color(image: &RgbImage, (x0, x1): (u32, u32), (y0, y1): (u32, u32)) -> Option<Color> {
+    let mut counts: HashMap<u8, (usize, Color)> = HashMap::new();
+    for y in y0..y1 {
+        for x in x0..x1 {
+            if let Some(color) = pixel_color(*image.get_pixel(x, y)) {
+                counts.entry(color as u8).or_insert((0, color)).0 += 1;
+            }
+        }
+    }
+    counts.into_values().max_by_key(|(count, _)| *count).map(|(_, color)| color)
+}
+
+/// Colors of the filled rows of the board, top to bottom.
+///
+/// A row is a horizontal band of tile colored pixels falling apart into five square
+/// tiles of the same size. The smaller bands, like the logo, are dropped.
+pub fn detect_grid(image: &RgbImage) -> Vec<Vec<Color>> {
+    let (width, height) = image.dimensions();
+    let is_tile = |x, y| pixel_color(*image.get_pixel(x, y)).is_some();
+
+    let bands = runs(height, |y| (0..width).filter(|x| is_tile(*x, y)).count() as u32 * 4 >= width);
+    let mut rows: Vec<(u32, Vec<Color>)> = Vec::new();
+    for (y0, y1) in bands {
+        let band_height = y1 - y0;
+        let tiles = runs(width, |x| (y0..y1).filter(|y| is_tile(x, *y)).count() as u32 * 2 >= band_height);
+        if tiles.len() != WORD_LENGTH {
+            continue;
+        }
+        let mut widths: Vec<u32> = tiles.iter().map(|(x0, x1)| x1 - x0).collect();
+        widths.sort();
+        let tile_width = widths[WORD_LENGTH / 2];
+        if !widths.iter().all(|w| is_about(*w, tile_width)) || !is_about(band_height, tile_width) {
+            continue;
+        }
+        let colors: Option<Vec<Color>> = tiles.iter().map(|x| dominant_color(image, *x, (y0, y1))).collect();
+        if let Some(colors) = colors {
+            rows.push((tile_width, colors));
+        }
+    }
+
+    let board_width = rows.iter().map(|(width, _)| *width).max().unwrap_or_default();
+    rows.into_iter()
+        .filter(|(width, _)| width * 4 >= board_width * 3)
+        .map(|(_, colors)| colors)
+        .collect()
+}
+
+/// Colors of the filled rows in the PNG or JPEG screenshot.
+pub fn read_screenshot(bytes: &[u8]) -> Result<Vec<Vec<Color>>, Error> {
+    let image = image::load_from_memory(bytes)
+        .map_err(|err| Error::Import(format!("Can't read the screenshot: {}", err)))?
+        .to_rgb8();
+    let grid = detect_grid(&image);
+    if grid.is_empty() {
+        return Err(Error::Import(String::from("No tile rows found in the screenshot")));
+    }
+    Ok(grid)
+}
+
+/// Rows in the `=`/`?` notation from the screenshot and the words typed for its rows.
+pub fn rows_from_screenshot(bytes: &[u8], words: &[String]) -> Result<Vec<String>, Error> {
+    rows_from_colors(words, &read_screenshot(bytes)?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use image::ImageFormat;
+
+    use super::*;
+    use crate::strings;
+    use Color::{Gray as G, White as W, Yellow as Y};
+
+    const SCREENSHOT: &[u8] = include_bytes!("../../screenshot.jpg");
+
+    /// PNG of the dark board with the rows of 10px tiles and 2px gaps.
+    fn board_png(grid: &[Vec<Color>]) -> Vec<u8> {
+        let mut image = RgbImage::from_pixel(80, 100, Rgb([28, 28, 30]));
+        for (row, colors) in grid.iter().enumerate() {
+            for (col, color) in colors.iter().enumerate() {
+                let pixel = match color {
+                    Color::Gray => Rgb([94, 94, 94]),
+                    Color::White => Rgb([255, 255, 255]),
+                    Color::Yellow => Rgb([254, 221, 46]),
+                };
+                for y in 0..10 {
+                    for x in 0..10 {
+                        image.put_pixel(10 + col as u32 * 12 + x, 20 + row as u32 * 12 + y, pixel);
+                    }
+                }
+            }
+        }
+        let mut png = Vec::new();
+        image.write_to(&mut Cursor::new(&mut png), ImageFormat::Png).unwrap();
+        png
+    }
+
+    #[test]
+    fn test_read_screenshot() {
+        assert_eq!(read_screenshot(SCREENSHOT).unwrap(), vec![
+            vec![W, G, G, W, G],
+            vec![G, G, G, W, G],
+            vec![G, W, G, G, G],
+            vec![G, G, G, G, G],
+            vec![Y, Y, Y, Y, Y],
+        ]);
+    }
+
+    #[test]
+    fn test_rows_from_screenshot() {
+        let rows = rows_from_screenshot(SCREENSHOT, &strings(&["лабет", "сяжок", "ингуш", "взрыд", "ОЛЕНЬ"])).unwrap();
+        assert_eq!(rows, vec!["?лаб?ет", "сяж?ок", "и?нгуш", "взрыд", "=о=л=е=н=ь"]);
+        assert_eq!(rows_from_screenshot(SCREENSHOT, &strings(&["лабет"])).unwrap_err().code(), "wrong_import");
+    }
+
+    #[test]
+    fn test_read_png() {
+        let grid = vec![vec![G, W, G, G, Y], vec![Y; 5]];
+        assert_eq!(read_screenshot(&board_png(&grid)).unwrap(), grid);
+    }
+
+    #[test]
+    fn test_no_grid() {
+        assert_eq!(read_screenshot(&board_png(&[])).unwrap_err().code(), "wrong_import");
+        assert_eq!(read_screenshot(b"not an image").unwrap_err().code(), "wrong_import");
+    }
+}
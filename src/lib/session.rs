@@ -0,0 +1,189 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+pub const BUILTIN_DICTIONARY: &str = "builtin";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    Solve,
+    Boards(usize),
+}
+
+/// Rows entered so far, kept between the program runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub name: String,
+    pub mode: Mode,
+    pub dictionary: String,
+    /// Rows of every board in the `=`/`?` notation.
+    pub boards: Vec<Vec<String>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Session {
+    pub fn new(name: &str, mode: Mode) -> Session {
+        let count = match mode {
+            Mode::Solve => 1,
+            Mode::Boards(count) => count,
+        };
+        let now = Utc::now();
+        Session {
+            name: String::from(name),
+            mode,
+            dictionary: String::from(BUILTIN_DICTIONARY),
+            boards: vec![Vec::new(); count],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Rows of the single board session.
+    pub fn rows(&self) -> &[String] {
+        self.boards.first().map(|b| b.as_slice()).unwrap_or_default()
+    }
+
+    pub fn add_rows(&mut self, rows: Vec<String>) {
+        if let Some(board) = self.boards.first_mut() {
+            board.extend(rows);
+        }
+        self.updated_at = Utc::now();
+    }
+
+    pub fn add_board_rows(&mut self, boards: Vec<Vec<String>>) {
+        for (board, rows) in self.boards.iter_mut().zip(boards) {
+            board.extend(rows);
+        }
+        self.updated_at = Utc::now();
+    }
+
+    pub fn clear(&mut self) {
+        self.boards.iter_mut().for_each(|b| b.clear());
+        self.updated_at = Utc::now();
+    }
+}
+
+/// Directory of the program inside the user data directory, e.g. `~/.local/share` on Linux.
+pub fn app_data_dir() -> io::Result<PathBuf> {
+    let data_dir = dirs::data_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "User data directory is not found"))?;
+    Ok(data_dir.join("tinkoff_guess_game_helper"))
+}
+
+/// Sessions saved as JSON files, one per session.
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    dir: PathBuf,
+}
+
+impl SessionStore {
+    pub fn new<P: AsRef<Path>>(dir: P) -> SessionStore {
+        SessionStore { dir: dir.as_ref().to_path_buf() }
+    }
+
+    /// The store in the user data directory.
+    pub fn open_default() -> io::Result<SessionStore> {
+        Ok(SessionStore::new(app_data_dir()?.join("sessions")))
+    }
+
+    fn path(&self, name: &str) -> io::Result<PathBuf> {
+        let valid = !name.is_empty() && name.chars().all(|ch| ch.is_alphanumeric() || ch == '-' || ch == '_');
+        if !valid {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Wrong session name: `{}`", name)));
+        }
+        Ok(self.dir.join(format!("{}.json", name)))
+    }
+
+    pub fn load(&self, name: &str) -> io::Result<Session> {
+        let data = fs::read_to_string(self.path(name)?)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Loads the session or starts a new one if there is nothing saved yet.
+    pub fn load_or_new(&self, name: &str, mode: Mode) -> io::Result<Session> {
+        match self.load(name) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Session::new(name, mode)),
+            result => result,
+        }
+    }
+
+    pub fn save(&self, session: &Session) -> io::Result<()> {
+        let path = self.path(&session.name)?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(path, serde_json::to_string_pretty(session)?)
+    }
+
+    pub fn delete(&self, name: &str) -> io::Result<()> {
+        fs::remove_file(self.path(name)?)
+    }
+
+    /// Names of the saved sessions in alphabetical order.
+    pub fn list(&self) -> io::Result<Vec<String>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                if let Some(stem) = path.file_stem() {
+                    names.push(stem.to_string_lossy().into_owned());
+                }
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_store(name: &str) -> SessionStore {
+        let dir = std::env::temp_dir().join(format!("tinkoff-sessions-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        SessionStore::new(dir)
+    }
+
+    #[test]
+    fn test_session_json() {
+        let mut session = Session::new("daily", Mode::Boards(2));
+        session.add_board_rows(vec![vec![String::from("лента")], vec![String::from("л?ента")]]);
+        let json = serde_json::to_string(&session).unwrap();
+        assert!(json.contains(r#""mode":{"boards":2}"#));
+        assert_eq!(serde_json::from_str::<Session>(&json).unwrap(), session);
+    }
+
+    #[test]
+    fn test_store() {
+        let store = temp_store("store");
+        assert_eq!(store.list().unwrap(), Vec::<String>::new());
+
+        let mut session = store.load_or_new("today", Mode::Solve).unwrap();
+        session.add_rows(vec![String::from("лента"), String::from("с=удь=я")]);
+        store.save(&session).unwrap();
+        store.save(&Session::new("another", Mode::Solve)).unwrap();
+        assert_eq!(store.list().unwrap(), vec!["another", "today"]);
+        assert_eq!(store.load("today").unwrap().rows(), ["лента", "с=удь=я"]);
+
+        store.delete("today").unwrap();
+        assert_eq!(store.list().unwrap(), vec!["another"]);
+        assert_eq!(store.load("today").unwrap_err().kind(), io::ErrorKind::NotFound);
+        fs::remove_dir_all(&store.dir).unwrap();
+    }
+
+    #[test]
+    fn test_wrong_name() {
+        let store = temp_store("names");
+        assert_eq!(store.load("../etc").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(store.save(&Session::new("", Mode::Solve)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+use crate::{words, Error, Letter};
+use crate::adversarial;
+use crate::game::{colored_letters, consistent_words, normalize_guess, score, MAX_ATTEMPTS};
+use crate::rank::best_guesses;
+
+/// Guesses beyond this are not played, the strategy is considered stuck.
+pub const ATTEMPTS_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    /// The guess giving the most information about the candidates.
+    Entropy,
+    /// The first candidate in the dictionary order.
+    FirstCandidate,
+}
+
+// Ranking the whole dictionary is slow and the result never changes.
+fn entropy_opener() -> &'static str {
+    static OPENER: OnceLock<&'static str> = OnceLock::new();
+    OPENER.get_or_init(|| best_guesses(words::WORDLIST, 1)[0].0)
+}
+
+impl Strategy {
+    pub fn next_guess(&self, rows: &[Vec<Letter>], candidates: &[&'static str]) -> &'static str {
+        match self {
+            Strategy::FirstCandidate => candidates[0],
+            Strategy::Entropy if rows.is_empty() => entropy_opener(),
+            Strategy::Entropy if candidates.len() <= 2 => candidates[0],
+            Strategy::Entropy => best_guesses(candidates, 1)[0].0,
+        }
+    }
+
+    /// Number of guesses to find the answer, not limited by MAX_ATTEMPTS.
+    /// The answer must be in the dictionary, otherwise the candidates run out.
+    pub fn play_out(&self, answer: &str) -> Result<usize, Error> {
+        let answer = normalize_guess(answer)?;
+        let mut rows: Vec<Vec<Letter>> = Vec::new();
+        while rows.len() < ATTEMPTS_LIMIT {
+            let candidates = consistent_words(&rows);
+            let guess = self.next_guess(&rows, &candidates);
+            rows.push(colored_letters(guess, &score(guess, &answer)));
+            if guess == answer {
+                break;
+            }
+        }
+        Ok(rows.len())
+    }
+
+    /// Guesses needed against the adversarial host, `None` if it never ends.
+    pub fn worst_case(&self) -> Option<usize> {
+        adversarial::worst_case(|rows, candidates| self.next_guess(rows, candidates), ATTEMPTS_LIMIT)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub games: usize,
+    /// Games solved within MAX_ATTEMPTS.
+    pub won: usize,
+    pub average: f64,
+    /// Number of the games solved with 1, 2, ... guesses.
+    pub distribution: Vec<usize>,
+    pub max_attempts: usize,
+}
+
+pub fn simulate(strategy: Strategy, answers: &[&'static str]) -> Result<Report, Error> {
+    let attempts = answers.iter().map(|answer| strategy.play_out(answer)).collect::<Result<Vec<_>, _>>()?;
+    let max_attempts = attempts.iter().max().copied().unwrap_or(0);
+    let mut distribution = vec![0; max_attempts];
+    attempts.iter().for_each(|n| distribution[n - 1] += 1);
+    Ok(Report {
+        games: answers.len(),
+        won: attempts.iter().filter(|n| **n <= MAX_ATTEMPTS).count(),
+        average: if answers.is_empty() { 0.0 } else { attempts.iter().sum::<usize>() as f64 / answers.len() as f64 },
+        distribution,
+        max_attempts,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_play_out() {
+        assert_eq!(Strategy::FirstCandidate.play_out(words::WORDLIST[0]), Ok(1));
+        assert!(Strategy::FirstCandidate.play_out("мумия").unwrap() > 1);
+    }
+
+    #[test]
+    fn test_entropy_next_guess() {
+        let rows = crate::strings_to_words(vec![String::from("лента"), String::from("с=удь=я")]);
+        let candidates = consistent_words(&rows);
+        assert_eq!(candidates, vec!["гурия", "курия", "мумия", "рупия", "фурия"]);
+        let guess = Strategy::Entropy.next_guess(&rows, &candidates);
+        assert_eq!(guess, best_guesses(&candidates, 1)[0].0);
+        assert_eq!(Strategy::Entropy.next_guess(&rows, &["мумия"]), "мумия");
+    }
+
+    #[test]
+    fn test_simulate() {
+        let report = simulate(Strategy::FirstCandidate, &["мумия", "лента", words::WORDLIST[0]]).unwrap();
+        assert_eq!(report.games, 3);
+        assert_eq!(report.distribution.iter().sum::<usize>(), 3);
+        assert_eq!(report.distribution[0], 1);
+        assert!(report.average >= 1.0);
+    }
+
+    #[test]
+    fn test_simulate_unknown_answer() {
+        let err = simulate(Strategy::FirstCandidate, &["мумия", "ыыыыы"]).unwrap_err();
+        assert_eq!(err.code(), "unknown_word");
+        assert!(Strategy::Entropy.play_out("ыыыыы").is_err());
+    }
+
+    #[test]
+    fn test_worst_case() {
+        assert!(Strategy::FirstCandidate.worst_case().is_some());
+    }
+}
@@ -0,0 +1,148 @@
+use serde::Serialize;
+
+use crate::{find_matches, parse_rows, stat_from_rows, suggest_words, words, Error};
+use crate::rank::{best_guesses, entropy};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Candidate {
+    pub word: &'static str,
+    /// Expected information of the word as the next guess, in bits.
+    pub score: f64,
+}
+
+impl From<(&'static str, f64)> for Candidate {
+    fn from((word, score): (&'static str, f64)) -> Candidate {
+        Candidate { word, score }
+    }
+}
+
+/// What is known about a single position of the answer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Position {
+    pub letter: Option<char>,
+    /// Letters which are in the answer, but not here.
+    pub excluded: Vec<char>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Solution {
+    pub remaining: usize,
+    pub candidates: Vec<Candidate>,
+    pub positions: Vec<Position>,
+    pub absent: Vec<char>,
+}
+
+/// Words which may still be the answer, the whole dictionary without rows.
+pub fn candidates(rows: &[String]) -> Result<Vec<&'static str>, Error> {
+    if rows.is_empty() {
+        return Ok(words::WORDLIST.to_vec());
+    }
+    let rows = parse_rows(rows)?;
+    Ok(find_matches(&stat_from_rows(&rows)?, &rows))
+}
+
+/// Best next guesses from the whole dictionary for the words left after the rows.
+pub fn ranking(rows: &[String], count: usize) -> Result<Vec<Candidate>, Error> {
+    Ok(best_guesses(&candidates(rows)?, count).into_iter().map(Candidate::from).collect())
+}
+
+/// Adds the row if it agrees with the previous ones and returns the candidates after it.
+/// A wrong or conflicting row leaves the rows as they were.
+pub fn try_push_row(rows: &mut Vec<String>, row: String) -> Result<Vec<&'static str>, Error> {
+    rows.push(row);
+    let result = candidates(rows);
+    if result.is_err() {
+        rows.pop();
+    }
+    result
+}
+
+/// Same as `guess_word` for the rows in the `=`/`?` notation, but with the details.
+/// Without rows the candidates are the suggested first words.
+pub fn solve(rows: &[String]) -> Result<Solution, Error> {
+    let letters = parse_rows(rows)?;
+    let stat = stat_from_rows(&letters)?;
+    let (remaining, words) = if rows.is_empty() {
+        (words::WORDLIST.len(), suggest_words())
+    } else {
+        let matches = find_matches(&stat, &letters);
+        (matches.len(), matches)
+    };
+    let pool: Vec<&str> = if rows.is_empty() { words::WORDLIST.to_vec() } else { words.clone() };
+    let candidates = words.iter().map(|word| Candidate { word, score: entropy(word, &pool) }).collect();
+
+    let positions = (0..5).map(|idx| {
+        let mut excluded = stat.white_letters.get(&idx).cloned().unwrap_or_default();
+        excluded.sort();
+        excluded.dedup();
+        Position { letter: stat.yellow_letters.get(&idx).copied(), excluded }
+    }).collect();
+    let mut absent: Vec<char> = stat.gray_letters.iter().copied().collect();
+    absent.sort();
+
+    Ok(Solution { remaining, candidates, positions, absent })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{letters_to_string, strings};
+    use crate::game::{colored_letters, score};
+
+    #[test]
+    fn test_solve() {
+        let solution = solve(&strings(&["лента", "с=удь=я", "?игрок"])).unwrap();
+        assert_eq!(solution.remaining, 1);
+        assert_eq!(solution.candidates, vec![Candidate { word: "мумия", score: 0.0 }]);
+        assert_eq!(solution.positions[1], Position { letter: Some('у'), excluded: vec![] });
+        assert_eq!(solution.positions[0], Position { letter: None, excluded: vec!['и'] });
+        assert!(solution.absent.contains(&'л'));
+    }
+
+    #[test]
+    fn test_solve_without_rows() {
+        let solution = solve(&[]).unwrap();
+        assert_eq!(solution.remaining, words::WORDLIST.len());
+        assert_eq!(solution.candidates.len(), 5);
+        assert!(solution.candidates.iter().all(|c| c.score > 0.0));
+    }
+
+    #[test]
+    fn test_ranking() {
+        let rows = strings(&["лента", "с=удь=я"]);
+        let best = ranking(&rows, 2).unwrap();
+        assert_eq!(best.len(), 2);
+        assert_eq!(Candidate::from(best_guesses(&candidates(&rows).unwrap(), 1)[0]), best[0]);
+        assert_eq!(ranking(&strings(&["лент"]), 2).unwrap_err().code(), "wrong_row");
+    }
+
+    #[test]
+    fn test_candidates() {
+        assert_eq!(candidates(&strings(&["лента", "с=удь=я", "?игрок"])).unwrap(), vec!["мумия"]);
+        assert_eq!(candidates(&[]).unwrap().len(), words::WORDLIST.len());
+    }
+
+    #[test]
+    fn test_candidates_agree_with_score() {
+        // Only the first `м` of `мумия` is colored for `смазь`, the second one is gray.
+        let row = letters_to_string(&colored_letters("мумия", &score("мумия", "смазь")));
+        assert_eq!(row, "?мумия");
+        assert!(candidates(&[row]).unwrap().contains(&"смазь"));
+        assert_eq!(candidates(&strings(&["=м=у=м=и=я"])).unwrap(), vec!["мумия"]);
+    }
+
+    #[test]
+    fn test_try_push_row() {
+        let mut rows = strings(&["лента"]);
+        assert_eq!(try_push_row(&mut rows, String::from("с=удь=я")).unwrap().len(), 5);
+        assert_eq!(try_push_row(&mut rows, String::from("лент")).unwrap_err().code(), "wrong_row");
+        assert_eq!(try_push_row(&mut rows, String::from("=лента")).unwrap_err().code(), "conflicting_rows");
+        assert_eq!(rows, strings(&["лента", "с=удь=я"]));
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert_eq!(solve(&strings(&["лент"])).unwrap_err().code(), "wrong_row");
+        assert_eq!(solve(&strings(&["лента", "=лента"])).unwrap_err().code(), "conflicting_rows");
+    }
+}
@@ -0,0 +1,74 @@
+// JavaScript API for the web pages without a backend. The rows are arrays
+// of strings in the `=`/`?` notation, the errors are `{code, message}` objects.
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::{Color, Error};
+
+#[derive(Serialize)]
+struct Tile {
+    letter: char,
+    color: &'static str,
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    serde_wasm_bindgen::to_value(value).map_err(JsValue::from)
+}
+
+fn to_js_error(err: Error) -> JsValue {
+    to_js(&err).unwrap_or_else(|err| err)
+}
+
+fn rows_from_js(rows: JsValue) -> Result<Vec<String>, JsValue> {
+    if rows.is_undefined() || rows.is_null() {
+        return Ok(Vec::new());
+    }
+    serde_wasm_bindgen::from_value(rows).map_err(JsValue::from)
+}
+
+/// Every row as an array of `{letter, color}` tiles.
+#[wasm_bindgen(js_name = parseRows)]
+pub fn parse_rows(rows: JsValue) -> Result<JsValue, JsValue> {
+    let rows = crate::parse_rows(&rows_from_js(rows)?).map_err(to_js_error)?;
+    let tiles: Vec<Vec<Tile>> = rows.iter().map(|row| row.iter().map(|letter| Tile {
+        letter: letter.letter(),
+        color: match letter.color() {
+            Color::Yellow => "yellow",
+            Color::White => "white",
+            Color::Gray => "gray",
+        },
+    }).collect()).collect();
+    to_js(&tiles)
+}
+
+#[wasm_bindgen]
+pub fn solve(rows: JsValue) -> Result<JsValue, JsValue> {
+    to_js(&crate::solve::solve(&rows_from_js(rows)?).map_err(to_js_error)?)
+}
+
+#[wasm_bindgen]
+pub fn candidates(rows: JsValue) -> Result<JsValue, JsValue> {
+    to_js(&crate::solve::candidates(&rows_from_js(rows)?).map_err(to_js_error)?)
+}
+
+/// Best next guesses as `{word, score}`, the score is in bits.
+#[wasm_bindgen(js_name = rankGuesses)]
+pub fn rank_guesses(rows: JsValue, count: usize) -> Result<JsValue, JsValue> {
+    to_js(&crate::solve::ranking(&rows_from_js(rows)?, count).map_err(to_js_error)?)
+}
+
+#[wasm_bindgen(js_name = explainWord)]
+pub fn explain_word(word: &str, rows: JsValue) -> Result<JsValue, JsValue> {
+    to_js(&crate::explain::explain_word(word, &rows_from_js(rows)?).map_err(to_js_error)?)
+}
+
+#[wasm_bindgen(js_name = keyboardState)]
+pub fn keyboard_state(rows: JsValue) -> Result<JsValue, JsValue> {
+    to_js(&crate::keyboard::keyboard_state(&rows_from_js(rows)?).map_err(to_js_error)?)
+}
+
+#[wasm_bindgen(js_name = importGame)]
+pub fn import_game(text: &str) -> Result<JsValue, JsValue> {
+    to_js(&crate::import::import_game(text).map_err(to_js_error)?)
+}
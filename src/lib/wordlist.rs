@@ -0,0 +1,177 @@
+// Maintenance of the word lists like `words.txt`, which the built-in dictionary is built from.
+// The order of the words is kept, so the changes of a list are easy to review.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use serde::Serialize;
+use serde::ser::{SerializeStruct, Serializer};
+
+use crate::game::WORD_LENGTH;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProblemKind {
+    WrongLength(usize),
+    /// Anything but the lowercase Russian letters.
+    WrongLetters,
+    /// The word is already on the given line.
+    Duplicate(usize),
+    /// The dictionary spells `ё` as `е`.
+    Yo,
+}
+
+impl ProblemKind {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProblemKind::WrongLength(_) => "wrong_length",
+            ProblemKind::WrongLetters => "wrong_letters",
+            ProblemKind::Duplicate(_) => "duplicate",
+            ProblemKind::Yo => "yo",
+        }
+    }
+}
+
+impl fmt::Display for ProblemKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProblemKind::WrongLength(len) => write!(f, "{} letters instead of {}", len, WORD_LENGTH),
+            ProblemKind::WrongLetters => write!(f, "only lowercase Russian letters are allowed"),
+            ProblemKind::Duplicate(line) => write!(f, "repeats line {}", line),
+            ProblemKind::Yo => write!(f, "ё is written as е"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    /// Line of the list, from 1.
+    pub line: usize,
+    pub word: String,
+    pub kind: ProblemKind,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.line, self.word, self.kind)
+    }
+}
+
+/// Serialized as `{"line": ..., "word": ..., "code": ..., "message": ...}`.
+impl Serialize for Problem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Problem", 4)?;
+        state.serialize_field("line", &self.line)?;
+        state.serialize_field("word", &self.word)?;
+        state.serialize_field("code", self.kind.code())?;
+        state.serialize_field("message", &self.kind.to_string())?;
+        state.end()
+    }
+}
+
+/// Words with their line numbers, the blank lines and the `#` comments are skipped.
+fn entries(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Words of the list, a word per line.
+pub fn parse_word_list(text: &str) -> Vec<String> {
+    entries(text).map(|(_, word)| String::from(word)).collect()
+}
+
+/// Everything wrong with the words of the list, in the order of the lines.
+pub fn validate(text: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for (line, word) in entries(text) {
+        let mut add = |kind| problems.push(Problem { line, word: String::from(word), kind });
+        let len = word.chars().count();
+        if len != WORD_LENGTH {
+            add(ProblemKind::WrongLength(len));
+        }
+        if word.contains('ё') {
+            add(ProblemKind::Yo);
+        } else if !word.chars().all(|ch| ('а'..='я').contains(&ch)) {
+            add(ProblemKind::WrongLetters);
+        }
+        match seen.get(word) {
+            Some(first) => add(ProblemKind::Duplicate(*first)),
+            None => {
+                seen.insert(word, line);
+            },
+        }
+    }
+    problems
+}
+
+/// The words without repeats, the first ones are kept.
+pub fn dedupe(words: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    words.iter().filter(|word| seen.insert(word.as_str())).cloned().collect()
+}
+
+/// Every word of the lists once, in the order the words first appear.
+pub fn merge(lists: &[Vec<String>]) -> Vec<String> {
+    dedupe(&lists.concat())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+pub fn diff(old: &[String], new: &[String]) -> Diff {
+    let old_words: HashSet<&String> = old.iter().collect();
+    let new_words: HashSet<&String> = new.iter().collect();
+    Diff {
+        added: dedupe(&new.iter().filter(|word| !old_words.contains(word)).cloned().collect::<Vec<_>>()),
+        removed: dedupe(&old.iter().filter(|word| !new_words.contains(word)).cloned().collect::<Vec<_>>()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strings;
+
+    #[test]
+    fn test_parse_word_list() {
+        assert_eq!(parse_word_list("# Словарь\nмумия\n\n  лента \n"), strings(&["мумия", "лента"]));
+    }
+
+    #[test]
+    fn test_validate() {
+        let problems = validate("мумия\nлент\nхи-хи\nёлочк\nМумия\nмумия\n");
+        let found: Vec<(usize, ProblemKind)> = problems.iter().map(|p| (p.line, p.kind.clone())).collect();
+        assert_eq!(found, vec![
+            (2, ProblemKind::WrongLength(4)),
+            (3, ProblemKind::WrongLetters),
+            (4, ProblemKind::Yo),
+            (5, ProblemKind::WrongLetters),
+            (6, ProblemKind::Duplicate(1)),
+        ]);
+        assert_eq!(problems[0].to_string(), "2: лент: 4 letters instead of 5");
+    }
+
+    #[test]
+    fn test_builtin_list_is_valid() {
+        let text = crate::words::WORDLIST.join("\n");
+        assert_eq!(validate(&text), vec![]);
+    }
+
+    #[test]
+    fn test_merge_and_dedupe() {
+        assert_eq!(dedupe(&strings(&["мумия", "лента", "мумия"])), strings(&["мумия", "лента"]));
+        let merged = merge(&[strings(&["мумия", "лента"]), strings(&["игрок", "мумия"])]);
+        assert_eq!(merged, strings(&["мумия", "лента", "игрок"]));
+    }
+
+    #[test]
+    fn test_diff() {
+        let diff = diff(&strings(&["мумия", "лента"]), &strings(&["лента", "игрок", "игрок"]));
+        assert_eq!(diff, Diff { added: strings(&["игрок"]), removed: strings(&["мумия"]) });
+    }
+}
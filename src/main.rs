@@ -1,38 +1,424 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+use chrono::Local;
 use clap::Parser;
-use tinkoff_guess_game_lib::{guess_word, strings_to_words, get_suggestions};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use tinkoff_guess_game_lib::{letters_to_string, Color, parse_row, parse_rows, get_suggestions};
+use tinkoff_guess_game_lib::daily::DailyWords;
+use tinkoff_guess_game_lib::adversarial::AdversarialGame;
+use tinkoff_guess_game_lib::analysis::analyze;
+use tinkoff_guess_game_lib::bot::{Bot, ConsoleTransport};
+use tinkoff_guess_game_lib::explain::explain_word;
+use tinkoff_guess_game_lib::frequency::dictionary_stats;
+use tinkoff_guess_game_lib::game::{consistent_words, is_consistent, is_known_word, is_row_solved, Game, Host, MAX_ATTEMPTS};
+use tinkoff_guess_game_lib::history::{statistics, GameRecord, History};
+use tinkoff_guess_game_lib::import::{grid_line, import_game, rows_from_colors};
+use tinkoff_guess_game_lib::keyboard::KeyboardState;
+use tinkoff_guess_game_lib::multiboard::MultiBoard;
+use tinkoff_guess_game_lib::screenshot::read_screenshot;
+use tinkoff_guess_game_lib::session::{Mode, Session, SessionStore};
+use tinkoff_guess_game_lib::simulate::{simulate, Strategy};
+use tinkoff_guess_game_lib::solve::{candidates, ranking, solve};
+use tinkoff_guess_game_lib::wordlist::{dedupe, diff, merge, parse_word_list, validate};
+use tinkoff_guess_game_lib::Error;
+
+use cli::{Args, Command, DictAction, SessionAction};
+use output::Format;
+use render::Renderer;
+
+mod cli;
+mod output;
+mod render;
+mod rpc;
+mod server;
+
+/// Same as `exit_with_lib_error` for the errors from outside of the library, `code` is for the scripts.
+fn exit_with_error(format: Format, code: &str, err: impl std::fmt::Display) -> ! {
+    output::print_failure(format, code, &err);
+    std::process::exit(2);
+}
+
+fn exit_with_io_error(format: Format, err: io::Error) -> ! {
+    exit_with_error(format, "io", err)
+}
+
+/// The interactive commands and the word list files have no other formats.
+fn require_text_format(format: Format, command: &str) {
+    if format != Format::Text {
+        exit_with_error(format, "unsupported_format", format!("`{}` supports only the text format", command));
+    }
+}
+
+fn open_store(format: Format) -> SessionStore {
+    SessionStore::open_default().unwrap_or_else(|err| exit_with_io_error(format, err))
+}
+
+fn load_session(format: Format, name: &str, mode: Mode) -> Session {
+    let session = open_store(format).load_or_new(name, mode).unwrap_or_else(|err| exit_with_io_error(format, err));
+    if session.mode != mode {
+        exit_with_error(format, "wrong_session", format!("Session `{}` is in {:?} mode", name, session.mode));
+    }
+    session
+}
+
+fn save_session(format: Format, session: &Session) {
+    open_store(format).save(session).unwrap_or_else(|err| exit_with_io_error(format, err));
+}
+
+fn manage_sessions(format: Format, action: SessionAction) {
+    let store = open_store(format);
+    match action {
+        SessionAction::List => {
+            let names = store.list().unwrap_or_else(|err| exit_with_io_error(format, err));
+            output::print_list(format, "name", &names.iter().map(String::as_str).collect::<Vec<_>>());
+        },
+        SessionAction::Show { name } => {
+            let session = store.load(&name).unwrap_or_else(|err| exit_with_io_error(format, err));
+            output::print_session(format, &session);
+        },
+        SessionAction::Delete { name } => store.delete(&name).unwrap_or_else(|err| exit_with_io_error(format, err)),
+    }
+}
+
+fn play(mut game: impl Host, record: bool, renderer: Renderer) {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut helper_used = false;
+    while !game.is_over() {
+        match game.max_attempts() {
+            Some(max) => print!("[{}/{}] > ", game.attempts() + 1, max),
+            None => print!("[{}] > ", game.attempts() + 1),
+        }
+        io::stdout().flush().unwrap();
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => break,
+        };
+        if line.trim() == "?" {
+            helper_used = true;
+            let candidates = consistent_words(game.rows());
+            println!("Вариантов: {}", candidates.len());
+            println!("{}", candidates.iter().take(10).copied().collect::<Vec<_>>().join(" "));
+            continue;
+        }
+        match game.guess(&line) {
+            Ok(row) => println!("{}", renderer.row(row)),
+            Err(err) => {
+                println!("{}", err);
+                continue;
+            },
+        }
+        if !game.is_over() {
+            println!("{}", renderer.keyboard(&KeyboardState::from_rows(game.rows())));
+        }
+    }
+    if game.is_won() {
+        println!("Угадано!");
+    } else if let Some(answer) = game.answer() {
+        println!("Загаданное слово: {}", answer);
+    }
+    println!();
+    println!("{}", game.share_grid());
+    if record && game.is_over() {
+        record_game(GameRecord {
+            date: Local::now().date_naive(),
+            rows: game.rows().iter().map(|row| letters_to_string(row)).collect(),
+            answer: game.answer().map(String::from),
+            attempts: game.attempts(),
+            won: game.is_won(),
+            helper_used,
+        });
+    }
+}
+
+fn record_game(record: GameRecord) {
+    if let Err(err) = History::open_default().and_then(|history| history.append(&record)) {
+        eprintln!("The game is not saved to the history: {}", err);
+    }
+}
 
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-struct Args {
-    /// SHould suggest initial words
-    #[arg(short, long, action)]
-    suggest: bool,
+/// Records the game solved with the helper once its last row comes in.
+/// The rows added after the end don't record the same game again.
+fn record_solved_session(session: &Session, new_rows: usize) {
+    let rows = session.rows();
+    let won = rows.last().and_then(|row| parse_row(row).ok()).is_some_and(|row| is_row_solved(&row));
+    if new_rows == 0 || !(won || rows.len() >= MAX_ATTEMPTS) || is_finished(&rows[..rows.len() - new_rows]) {
+        return;
+    }
+    record_game(GameRecord {
+        date: Local::now().date_naive(),
+        rows: rows.to_vec(),
+        answer: won.then(|| rows.last().unwrap().replace('=', "")),
+        attempts: rows.len(),
+        won,
+        helper_used: true,
+    });
+}
 
-    /// Used words
-    #[arg(short, long, value_parser, num_args = 0.. , value_delimiter=' ')]
-    words: Vec<String>,
+fn is_finished(rows: &[String]) -> bool {
+    rows.len() >= MAX_ATTEMPTS || rows.iter().any(|row| parse_row(row).is_ok_and(|row| is_row_solved(&row)))
 }
 
+fn analyze_game(format: Format, answer: &str, rows: &[String]) {
+    let guesses: Vec<String> = rows.iter().map(|row| row.replace(['=', '?', '^'], "")).collect();
+    let guesses: Vec<&str> = guesses.iter().map(|g| g.as_str()).collect();
+    let analysis = analyze(&guesses, answer).unwrap_or_else(|err| exit_with_lib_error(format, err.into()));
+    output::print_analysis(format, &analysis);
+}
+
+fn show_stats(format: Format) {
+    let records = History::open_default()
+        .and_then(|history| history.load())
+        .unwrap_or_else(|err| exit_with_io_error(format, err));
+    output::print_statistics(format, &statistics(&records));
+}
+
+fn solve_boards(format: Format, boards: Vec<String>, count: usize, session: Option<String>) {
+    let mut boards: Vec<Vec<String>> = boards.iter()
+        .map(|board| board.split_whitespace().map(String::from).collect())
+        .collect();
+    let session = session.map(|name| {
+        let mut session = load_session(format, &name, Mode::Boards(boards.len()));
+        session.add_board_rows(boards.clone());
+        session
+    });
+    if let Some(session) = &session {
+        boards = session.boards.clone();
+    }
+    let rows = boards.iter()
+        .map(|rows| parse_rows(rows))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|err| exit_with_lib_error(format, err));
+    let multiboard = MultiBoard::from_boards(rows).unwrap_or_else(|err| exit_with_lib_error(format, err));
+    // Only the rows which make sense are kept in the session.
+    if let Some(session) = session {
+        save_session(format, &session);
+    }
+    output::print_boards(format, &multiboard, &multiboard.recommend(count));
+}
+
+fn exit_with_lib_error(format: Format, err: Error) -> ! {
+    output::print_error(format, &err);
+    std::process::exit(2);
+}
+
+fn solve_rows(format: Format, rows: Vec<String>, session: Option<String>, renderer: Renderer) {
+    let new_rows = rows.len();
+    let session = session.map(|name| {
+        let mut session = load_session(format, &name, Mode::Solve);
+        session.add_rows(rows.clone());
+        session
+    });
+    let rows = session.as_ref().map(|s| s.rows().to_vec()).unwrap_or(rows);
+    let letters = parse_rows(&rows).unwrap_or_else(|err| exit_with_lib_error(format, err));
+    let solution = solve(&rows).unwrap_or_else(|err| exit_with_lib_error(format, err));
+    // Only the rows which make sense are kept in the session.
+    if let Some(session) = session {
+        save_session(format, &session);
+        record_solved_session(&session, new_rows);
+    }
+    // The tiles are for the eyes only, the scripts get the words.
+    if format == Format::Text && !rows.is_empty() && io::stdout().is_terminal() {
+        letters.iter().for_each(|row| println!("{}", renderer.row(row)));
+        println!();
+        println!("{}", renderer.keyboard(&KeyboardState::from_rows(&letters)));
+        println!("-----");
+    }
+    output::print_solution(format, &solution);
+}
+
+fn import_rows(format: Format, path: &Path) -> Vec<String> {
+    let text = if path == Path::new("-") {
+        io::read_to_string(io::stdin())
+    } else {
+        std::fs::read_to_string(path)
+    };
+    let text = text.unwrap_or_else(|err| exit_with_io_error(format, err));
+    import_game(&text).unwrap_or_else(|err| exit_with_lib_error(format, err))
+}
+
+fn screenshot_rows(format: Format, path: &Path, words: Vec<String>) -> Vec<String> {
+    let bytes = std::fs::read(path).unwrap_or_else(|err| exit_with_io_error(format, err));
+    let grid = read_screenshot(&bytes).unwrap_or_else(|err| exit_with_lib_error(format, err));
+    let words = if words.is_empty() { ask_words(&grid) } else { words };
+    rows_from_colors(&words, &grid).unwrap_or_else(|err| exit_with_lib_error(format, err))
+}
+
+/// Asks the word of every row found in the screenshot, the prompts go to stderr.
+fn ask_words(grid: &[Vec<Color>]) -> Vec<String> {
+    let mut lines = io::stdin().lock().lines();
+    grid.iter().map(|colors| {
+        eprint!("{} ", grid_line(colors));
+        lines.next().and_then(Result::ok).map(|line| String::from(line.trim())).unwrap_or_default()
+    }).collect()
+}
+
+fn rank_rows(format: Format, rows: &[String], count: usize) {
+    let ranking = ranking(rows, count).unwrap_or_else(|err| exit_with_lib_error(format, err));
+    output::print_ranking(format, &ranking);
+}
+
+fn run_simulation(format: Format, strategy: Strategy, games: Option<usize>, seed: Option<u64>, worst_case: bool) {
+    let mut answers = candidates(&[]).unwrap();
+    if let Some(games) = games {
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        answers.shuffle(&mut rng);
+        answers.truncate(games);
+    }
+    let report = simulate(strategy, &answers).unwrap_or_else(|err| exit_with_lib_error(format, err));
+    let worst_case = worst_case.then(|| strategy.worst_case());
+    output::print_report(format, &report, worst_case);
+}
+
+fn read_list(format: Format, path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap_or_else(|err| exit_with_io_error(format, err))
+}
+
+/// Words of the list file, a word per line, or the built-in dictionary.
+fn dictionary_words(format: Format, path: Option<&Path>) -> Vec<String> {
+    match path {
+        Some(path) => parse_word_list(&read_list(format, path)),
+        None => candidates(&[]).unwrap().iter().map(|word| String::from(*word)).collect(),
+    }
+}
+
+/// Writes the words a line per word to the file or to stdout.
+fn write_words(format: Format, words: &[String], output: Option<&Path>) {
+    let mut text = words.join("\n");
+    text.push('\n');
+    match output {
+        Some(path) => std::fs::write(path, text).unwrap_or_else(|err| exit_with_io_error(format, err)),
+        None => print!("{}", text),
+    }
+}
+
+fn look_into_dictionary(format: Format, action: DictAction) {
+    match action {
+        DictAction::List => output::print_list(format, "word", &candidates(&[]).unwrap()),
+        DictAction::Check { words } => {
+            let words: Vec<(String, bool)> = words.into_iter()
+                .map(|word| {
+                    let known = is_known_word(&word.to_lowercase());
+                    (word, known)
+                })
+                .collect();
+            output::print_known_words(format, &words);
+            if !words.iter().all(|(_, known)| *known) {
+                std::process::exit(1);
+            }
+        },
+        DictAction::Stats { rows, dictionary, count } => {
+            let rows = parse_rows(&rows).unwrap_or_else(|err| exit_with_lib_error(format, err));
+            let words = dictionary_words(format, dictionary.as_deref());
+            let words: Vec<&str> = words.iter()
+                .map(String::as_str)
+                .filter(|word| rows.iter().all(|row| is_consistent(row, word)))
+                .collect();
+            let mut stats = dictionary_stats(&words);
+            stats.bigrams.truncate(count);
+            output::print_dictionary_stats(format, &stats);
+        },
+        DictAction::Validate { file } => {
+            let text = match file {
+                Some(path) => read_list(format, &path),
+                None => dictionary_words(format, None).join("\n"),
+            };
+            let problems = validate(&text);
+            output::print_problems(format, &problems);
+            if !problems.is_empty() {
+                std::process::exit(1);
+            }
+        },
+        DictAction::Dedupe { file, output } => {
+            require_text_format(format, "dict dedupe");
+            write_words(format, &dedupe(&parse_word_list(&read_list(format, &file))), output.as_deref());
+        },
+        DictAction::Merge { files, output } => {
+            require_text_format(format, "dict merge");
+            let lists: Vec<Vec<String>> = files.iter().map(|path| parse_word_list(&read_list(format, path))).collect();
+            write_words(format, &merge(&lists), output.as_deref());
+        },
+        DictAction::Diff { old, new } => {
+            let diff = diff(&parse_word_list(&read_list(format, &old)), &parse_word_list(&read_list(format, &new)));
+            output::print_diff(format, &diff);
+        },
+    }
+}
 
 fn main() {
     /*
-     * The yellow letters are prefixed by `=`.
-     * The gray letters have no prefixes.
-     * The white letters are prefixed by `?`.
-     *
-     * $ cargo run --bin console_app --words =ямн=д=а
+     * $ cargo run --bin main -- solve =ямн=д=а
      * ябеда
      * ягода
      *
+     * The old `--words`/`--suggest` flags still work without a subcommand.
      */
     let args = Args::parse();
-    if args.suggest {
-        get_suggestions().iter().for_each(|s| {
-            s.iter().for_each(|w| {println!("{}", w);});
-            println!("-----")
-        });
-
-    } else {
-        guess_word(strings_to_words(args.words)).iter().for_each(|word| {println!("{}", word);});
+    let format = args.format;
+    let renderer = Renderer::new(args.no_color);
+    match args.command {
+        Some(Command::Solve { rows, import, screenshot }) => {
+            let mut imported = import.map(|path| import_rows(format, &path)).unwrap_or_default();
+            match screenshot {
+                Some(path) => imported.extend(screenshot_rows(format, &path, rows)),
+                None => imported.extend(rows),
+            }
+            solve_rows(format, imported, args.session, renderer);
+        },
+        Some(Command::Suggest { count }) => {
+            let mut suggestions = get_suggestions();
+            if let Some(count) = count {
+                suggestions.truncate(count);
+            }
+            output::print_suggestions(format, &suggestions);
+        },
+        Some(Command::Rank { rows, count }) => rank_rows(format, &rows, count),
+        Some(Command::Explain { word, rows }) => {
+            let explanation = explain_word(&word, &rows).unwrap_or_else(|err| exit_with_lib_error(format, err));
+            output::print_explanation(format, &explanation);
+        },
+        Some(Command::Simulate { strategy, games, all, seed, worst_case }) => {
+            run_simulation(format, strategy.into(), (!all).then_some(games), seed, worst_case);
+        },
+        Some(Command::Serve { host, port }) => {
+            require_text_format(format, "serve");
+            server::serve(&format!("{}:{}", host, port)).unwrap_or_else(|err| exit_with_error(format, "io", err));
+        },
+        Some(Command::Rpc) => {
+            require_text_format(format, "rpc");
+            rpc::run(io::stdin().lock(), io::stdout().lock()).unwrap_or_else(|err| exit_with_io_error(format, err));
+        },
+        Some(Command::Bot) => {
+            require_text_format(format, "bot");
+            let mut transport = ConsoleTransport::new(io::stdin().lock(), io::stdout().lock(), "console");
+            Bot::new().run(&mut transport).unwrap_or_else(|err| exit_with_io_error(format, err));
+        },
+        Some(Command::Dict { action }) => look_into_dictionary(format, action),
+        Some(Command::Boards { boards, count }) => solve_boards(format, boards, count, args.session),
+        Some(Command::Sessions { action }) => manage_sessions(format, action),
+        Some(Command::Analyze { answer, rows }) => analyze_game(format, &answer, &rows),
+        Some(Command::Stats) => show_stats(format),
+        Some(Command::Play { .. }) if format != Format::Text => require_text_format(format, "play"),
+        Some(Command::Play { adversarial: true, .. }) => play(AdversarialGame::new(), false, renderer),
+        Some(Command::Play { seed, daily, date, salt, window, .. }) => {
+            let date = date.or(daily.then(|| Local::now().date_naive()));
+            let game = match (seed, date) {
+                (Some(seed), _) => Game::with_seed(seed),
+                (_, Some(date)) => match DailyWords::new(&salt, window) {
+                    Ok(daily) => Game::new(daily.word(date)),
+                    Err(err) => exit_with_lib_error(format, err),
+                },
+                _ => Game::random(),
+            };
+            play(game, true, renderer);
+        },
+        None if args.suggest => output::print_suggestions(format, &get_suggestions()),
+        None => solve_rows(format, args.words, args.session, renderer),
     }
 }
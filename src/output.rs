@@ -0,0 +1,335 @@
+use std::fmt::Display;
+
+use chrono::Local;
+use clap::ValueEnum;
+use serde_json::json;
+use tinkoff_guess_game_lib::Error;
+use tinkoff_guess_game_lib::analysis::RowAnalysis;
+use tinkoff_guess_game_lib::explain::Explanation;
+use tinkoff_guess_game_lib::frequency::DictionaryStats;
+use tinkoff_guess_game_lib::history::Statistics;
+use tinkoff_guess_game_lib::multiboard::MultiBoard;
+use tinkoff_guess_game_lib::session::Session;
+use tinkoff_guess_game_lib::simulate::Report;
+use tinkoff_guess_game_lib::solve::{Candidate, Solution};
+use tinkoff_guess_game_lib::wordlist::{Diff, Problem};
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Tsv,
+}
+
+impl Format {
+    fn separator(self) -> &'static str {
+        match self {
+            Format::Tsv => "\t",
+            _ => ",",
+        }
+    }
+}
+
+pub fn print_solution(format: Format, solution: &Solution) {
+    match format {
+        Format::Text => solution.candidates.iter().for_each(|c| println!("{}", c.word)),
+        Format::Json => println!("{}", serde_json::to_string_pretty(solution).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("word{}score", sep);
+            solution.candidates.iter().for_each(|c| println!("{}{}{:.4}", c.word, sep, c.score));
+        },
+    }
+}
+
+pub fn print_suggestions(format: Format, suggestions: &[Vec<&str>]) {
+    match format {
+        Format::Text => suggestions.iter().for_each(|s| {
+            s.iter().for_each(|w| println!("{}", w));
+            println!("-----")
+        }),
+        Format::Json => println!("{}", serde_json::to_string_pretty(suggestions).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("group{}word", sep);
+            for (idx, group) in suggestions.iter().enumerate() {
+                group.iter().for_each(|w| println!("{}{}{}", idx + 1, sep, w));
+            }
+        },
+    }
+}
+
+pub fn print_ranking(format: Format, ranking: &[Candidate]) {
+    match format {
+        Format::Text => ranking.iter().for_each(|c| println!("{} {:.2}", c.word, c.score)),
+        Format::Json => println!("{}", serde_json::to_string_pretty(ranking).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("word{}score", sep);
+            ranking.iter().for_each(|c| println!("{}{}{:.4}", c.word, sep, c.score));
+        },
+    }
+}
+
+pub fn print_explanation(format: Format, explanation: &Explanation) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(explanation).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("word{sep}in_dictionary{sep}candidate{sep}remaining{sep}bits{sep}groups{sep}largest_group");
+            println!(
+                "{}{sep}{}{sep}{}{sep}{}{sep}{:.4}{sep}{}{sep}{}",
+                explanation.word, explanation.in_dictionary, explanation.candidate, explanation.remaining,
+                explanation.bits, explanation.groups, explanation.largest_group,
+            );
+        },
+        Format::Text => {
+            println!("{}", explanation.word);
+            if !explanation.in_dictionary {
+                println!("Нет в словаре");
+            }
+            if explanation.candidate {
+                println!("Может быть ответом");
+            }
+            explanation.conflicts.iter().for_each(|conflict| println!("{}", conflict));
+            println!("Вариантов: {}", explanation.remaining);
+            println!("Бит: {:.2}, групп: {}, худший случай: {}", explanation.bits, explanation.groups, explanation.largest_group);
+        },
+    }
+}
+
+pub fn print_report(format: Format, report: &Report, worst_case: Option<Option<usize>>) {
+    match format {
+        Format::Json => {
+            let mut value = serde_json::to_value(report).unwrap();
+            if let Some(worst_case) = worst_case {
+                value["worst_case"] = json!(worst_case);
+            }
+            println!("{}", serde_json::to_string_pretty(&value).unwrap());
+        },
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("attempts{}games", sep);
+            for (idx, count) in report.distribution.iter().enumerate() {
+                println!("{}{}{}", idx + 1, sep, count);
+            }
+        },
+        Format::Text => {
+            println!("Игр: {}, угадано: {}, в среднем попыток: {:.3}", report.games, report.won, report.average);
+            for (idx, count) in report.distribution.iter().enumerate() {
+                println!("{} {}", idx + 1, count);
+            }
+            match worst_case {
+                Some(Some(attempts)) => println!("Против adversarial: {}", attempts),
+                Some(None) => println!("Против adversarial: не угадано"),
+                None => (),
+            }
+        },
+    }
+}
+
+pub fn print_dictionary_stats(format: Format, stats: &DictionaryStats) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(stats).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("letter{sep}count{sep}words{sep}share{sep}1{sep}2{sep}3{sep}4{sep}5");
+            for letter in stats.letters.iter() {
+                let positions: Vec<String> = letter.positions.iter().map(|count| count.to_string()).collect();
+                println!("{}{sep}{}{sep}{}{sep}{:.4}{sep}{}", letter.letter, letter.count, letter.words, letter.share, positions.join(sep));
+            }
+        },
+        Format::Text => {
+            let repeated = stats.repeated_letters.len();
+            let share = if stats.words == 0 { 0.0 } else { repeated as f64 * 100.0 / stats.words as f64 };
+            println!("Слов: {}, с повторами букв: {} ({:.1}%)", stats.words, repeated, share);
+            println!();
+            println!("Буква  Всего   Слов   Доля      1     2     3     4     5");
+            for letter in stats.letters.iter() {
+                let positions: Vec<String> = letter.positions.iter().map(|count| format!("{:>5}", count)).collect();
+                println!("{:<5} {:>6} {:>6} {:>5.1}%  {}", letter.letter, letter.count, letter.words, letter.share * 100.0, positions.join(" "));
+            }
+            println!("-----");
+            let bigrams: Vec<String> = stats.bigrams.iter().map(|b| format!("{} {}", b.bigram, b.count)).collect();
+            println!("Биграммы: {}", bigrams.join(", "));
+        },
+    }
+}
+
+pub fn print_problems(format: Format, problems: &[Problem]) {
+    match format {
+        Format::Text => problems.iter().for_each(|problem| println!("{}", problem)),
+        Format::Json => println!("{}", serde_json::to_string_pretty(problems).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("line{sep}word{sep}code");
+            problems.iter().for_each(|p| println!("{}{sep}{}{sep}{}", p.line, p.word, p.kind.code()));
+        },
+    }
+}
+
+pub fn print_diff(format: Format, diff: &Diff) {
+    match format {
+        Format::Text => {
+            diff.added.iter().for_each(|word| println!("+{}", word));
+            diff.removed.iter().for_each(|word| println!("-{}", word));
+        },
+        Format::Json => println!("{}", serde_json::to_string_pretty(diff).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("change{sep}word");
+            diff.added.iter().for_each(|word| println!("added{sep}{}", word));
+            diff.removed.iter().for_each(|word| println!("removed{sep}{}", word));
+        },
+    }
+}
+
+/// A value per line, `column` names them in the CSV header.
+pub fn print_list(format: Format, column: &str, values: &[&str]) {
+    match format {
+        Format::Text => values.iter().for_each(|value| println!("{}", value)),
+        Format::Json => println!("{}", serde_json::to_string_pretty(values).unwrap()),
+        Format::Csv | Format::Tsv => {
+            println!("{}", column);
+            values.iter().for_each(|value| println!("{}", value));
+        },
+    }
+}
+
+pub fn print_known_words(format: Format, words: &[(String, bool)]) {
+    match format {
+        Format::Text => words.iter().for_each(|(word, known)| println!("{} {}", word, if *known { "+" } else { "-" })),
+        Format::Json => {
+            let words: Vec<_> = words.iter().map(|(word, known)| json!({ "word": word, "known": known })).collect();
+            println!("{}", serde_json::to_string_pretty(&words).unwrap());
+        },
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("word{sep}known");
+            words.iter().for_each(|(word, known)| println!("{}{sep}{}", word, known));
+        },
+    }
+}
+
+pub fn print_boards(format: Format, multiboard: &MultiBoard, recommended: &[Candidate]) {
+    match format {
+        Format::Text => {
+            for (idx, board) in multiboard.boards().iter().enumerate() {
+                match board.solved() {
+                    Some(word) => println!("#{}: {} (угадано)", idx + 1, word),
+                    None => println!("#{}: {}", idx + 1, board.candidates().join(" ")),
+                }
+            }
+            if !multiboard.is_solved() {
+                println!("-----");
+                recommended.iter().for_each(|c| println!("{} {:.2}", c.word, c.score));
+            }
+        },
+        Format::Json => {
+            let boards: Vec<_> = multiboard.boards().iter()
+                .map(|board| json!({ "solved": board.solved(), "candidates": board.candidates() }))
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&json!({ "boards": boards, "recommended": recommended })).unwrap());
+        },
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("board{sep}word{sep}solved");
+            for (idx, board) in multiboard.boards().iter().enumerate() {
+                match board.solved() {
+                    Some(word) => println!("{}{sep}{}{sep}true", idx + 1, word),
+                    None => board.candidates().iter().for_each(|word| println!("{}{sep}{}{sep}false", idx + 1, word)),
+                }
+            }
+        },
+    }
+}
+
+pub fn print_analysis(format: Format, analysis: &[RowAnalysis]) {
+    let regret: f64 = analysis.iter().map(|row| row.regret()).sum();
+    match format {
+        Format::Text => {
+            println!("{:<12} {:>6} {:>6} {:>8} {:>8}   {:<6} {:>8}", "", "до", "после", "ожид.", "факт.", "лучше", "бит");
+            for row in analysis.iter() {
+                println!(
+                    "{:<12} {:>6} {:>6} {:>8.2} {:>8.2}   {:<6} {:>8.2}",
+                    row.row, row.before, row.after, row.expected_bits, row.actual_bits, row.best_guess, row.best_bits,
+                );
+            }
+            println!("Потеряно бит: {:.2}", regret);
+        },
+        Format::Json => println!("{}", serde_json::to_string_pretty(&json!({ "rows": analysis, "regret": regret })).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("row{sep}before{sep}after{sep}expected_bits{sep}actual_bits{sep}best_guess{sep}best_bits");
+            for row in analysis.iter() {
+                println!(
+                    "{}{sep}{}{sep}{}{sep}{:.4}{sep}{:.4}{sep}{}{sep}{:.4}",
+                    row.row, row.before, row.after, row.expected_bits, row.actual_bits, row.best_guess, row.best_bits,
+                );
+            }
+        },
+    }
+}
+
+pub fn print_statistics(format: Format, stats: &Statistics) {
+    match format {
+        Format::Text => {
+            println!("Сыграно: {}", stats.played);
+            println!("Угадано: {} ({:.0}%)", stats.won, stats.win_rate * 100.0);
+            println!("Серия: {}, лучшая: {}", stats.current_streak, stats.max_streak);
+            println!();
+            let max = stats.distribution.iter().max().copied().unwrap_or(0).max(1);
+            for (idx, count) in stats.distribution.iter().enumerate() {
+                println!("{} {:<20} {}", idx + 1, "#".repeat(count * 20 / max), count);
+            }
+            println!();
+            for (idx, remaining) in stats.average_remaining.iter().enumerate() {
+                println!("После {}-й строки осталось вариантов в среднем: {:.1}", idx + 1, remaining);
+            }
+        },
+        Format::Json => println!("{}", serde_json::to_string_pretty(stats).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("attempts{}games", sep);
+            for (idx, count) in stats.distribution.iter().enumerate() {
+                println!("{}{}{}", idx + 1, sep, count);
+            }
+        },
+    }
+}
+
+pub fn print_session(format: Format, session: &Session) {
+    match format {
+        Format::Text => {
+            println!("{:?}, {}", session.mode, session.updated_at.with_timezone(&Local).format("%Y-%m-%d %H:%M"));
+            for (idx, rows) in session.boards.iter().enumerate() {
+                println!("#{}: {}", idx + 1, rows.join(" "));
+            }
+        },
+        Format::Json => println!("{}", serde_json::to_string_pretty(session).unwrap()),
+        Format::Csv | Format::Tsv => {
+            let sep = format.separator();
+            println!("board{}row", sep);
+            for (idx, rows) in session.boards.iter().enumerate() {
+                rows.iter().for_each(|row| println!("{}{}{}", idx + 1, sep, row));
+            }
+        },
+    }
+}
+
+/// JSON errors go to stdout for the scripts, the rest to stderr.
+pub fn print_error(format: Format, err: &Error) {
+    print_failure(format, err.code(), err);
+}
+
+/// Same as `print_error` for the errors from outside of the library, like a missing file.
+pub fn print_failure(format: Format, code: &str, message: &dyn Display) {
+    match format {
+        Format::Json => {
+            let error = json!({ "error": { "code": code, "message": message.to_string() } });
+            println!("{}", serde_json::to_string_pretty(&error).unwrap());
+        },
+        _ => eprintln!("{}", message),
+    }
+}
@@ -0,0 +1,93 @@
+use tinkoff_guess_game_lib::{Color, Letter};
+use tinkoff_guess_game_lib::keyboard::{KeyboardState, LetterStatus};
+
+const RESET: &str = "\x1b[0m";
+const UNKNOWN_STYLE: &str = "\x1b[30;47m";
+
+const KEYBOARD: [&str; 3] = ["йцукенгшщзхъ", "фывапролджэ", "ячсмитьбю"];
+
+fn tile_style(color: Color) -> &'static str {
+    match color {
+        Color::Yellow => "\x1b[30;103m",
+        Color::White => "\x1b[30;107m",
+        Color::Gray => "\x1b[97;100m",
+    }
+}
+
+/// Draws the rows as colored tiles, or with brackets when the colors are off.
+#[derive(Debug, Clone, Copy)]
+pub struct Renderer {
+    color: bool,
+}
+
+impl Renderer {
+    /// The colors are off by `--no-color` or a non-empty `NO_COLOR` variable.
+    pub fn new(no_color: bool) -> Renderer {
+        let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        Renderer { color: !(no_color || no_color_env) }
+    }
+
+    /// Without the colors: `[С]` yellow, `(С)` white, ` С ` gray, ` · ` absent key.
+    fn tile(&self, letter: char, color: Option<Color>) -> String {
+        let letter = letter.to_uppercase();
+        match (self.color, color) {
+            (true, Some(color)) => format!("{} {} {}", tile_style(color), letter, RESET),
+            (true, None) => format!("{} {} {}", UNKNOWN_STYLE, letter, RESET),
+            (false, Some(Color::Yellow)) => format!("[{}]", letter),
+            (false, Some(Color::White)) => format!("({})", letter),
+            (false, _) => format!(" {} ", letter),
+        }
+    }
+
+    pub fn row(&self, row: &[Letter]) -> String {
+        row.iter()
+            .map(|l| self.tile(l.letter(), Some(l.color())))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The keyboard where the present letters are yellow or white, the absent ones gray.
+    pub fn keyboard(&self, state: &KeyboardState) -> String {
+        KEYBOARD.iter().enumerate().map(|(idx, keys)| {
+            let keys: Vec<String> = keys.chars().map(|key| match state.status(key) {
+                LetterStatus::Correct => self.tile(key, Some(Color::Yellow)),
+                LetterStatus::Present => self.tile(key, Some(Color::White)),
+                LetterStatus::Absent if !self.color => String::from(" · "),
+                LetterStatus::Absent => self.tile(key, Some(Color::Gray)),
+                LetterStatus::Unknown => self.tile(key, None),
+            }).collect();
+            format!("{}{}", " ".repeat(idx * 2), keys.join(""))
+        }).collect::<Vec<_>>().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tinkoff_guess_game_lib::string_to_letters;
+
+    #[test]
+    fn test_plain_row() {
+        let renderer = Renderer { color: false };
+        assert_eq!(renderer.row(&string_to_letters(&String::from("с=уд?ья"))), " С  [У]  Д  (Ь)  Я ");
+    }
+
+    #[test]
+    fn test_colored_row() {
+        let renderer = Renderer { color: true };
+        let row = renderer.row(&string_to_letters(&String::from("судь=я")));
+        assert!(row.ends_with("\x1b[30;103m Я \x1b[0m"));
+        assert!(row.starts_with("\x1b[97;100m С \x1b[0m"));
+    }
+
+    #[test]
+    fn test_plain_keyboard() {
+        let renderer = Renderer { color: false };
+        let keyboard = renderer.keyboard(&KeyboardState::from_rows(&[string_to_letters(&String::from("с=удья")), string_to_letters(&String::from("к?урок"))]));
+        let lines: Vec<&str> = keyboard.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with(" Й  Ц [У]"));
+        assert!(lines[1].contains(" · "));
+        assert!(lines[2].starts_with("     ·  Ч  · "));
+    }
+}
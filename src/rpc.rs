@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tinkoff_guess_game_lib::Error;
+use tinkoff_guess_game_lib::explain::explain_word;
+use tinkoff_guess_game_lib::rank::DEFAULT_RANK_COUNT;
+use tinkoff_guess_game_lib::solve::{candidates, ranking, try_push_row};
+
+// The codes of the JSON-RPC 2.0 spec, the solver errors carry their own code in `data`.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SOLVER_ERROR: i64 = -32000;
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    jsonrpc: String,
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionParams {
+    session: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RowParams {
+    session: u64,
+    row: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RankParams {
+    session: u64,
+    count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExplainParams {
+    session: u64,
+    word: String,
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl ToString) -> RpcError {
+        RpcError { code, message: message.to_string(), data: None }
+    }
+}
+
+impl From<Error> for RpcError {
+    fn from(err: Error) -> RpcError {
+        RpcError { code: SOLVER_ERROR, message: err.to_string(), data: Some(json!({ "code": err.code() })) }
+    }
+}
+
+fn params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|err| RpcError::new(INVALID_PARAMS, err))
+}
+
+/// The sessions live as long as the process, every one is a list of rows.
+#[derive(Default)]
+pub struct Rpc {
+    sessions: HashMap<u64, Vec<String>>,
+    next_session: u64,
+}
+
+impl Rpc {
+    fn rows(&self, session: u64) -> Result<&Vec<String>, RpcError> {
+        self.sessions.get(&session).ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("Unknown session {}", session)))
+    }
+
+    fn call(&mut self, method: &str, params_value: Value) -> Result<Value, RpcError> {
+        match method {
+            "session.new" => {
+                self.next_session += 1;
+                self.sessions.insert(self.next_session, Vec::new());
+                Ok(json!({ "session": self.next_session }))
+            },
+            "session.add_row" => {
+                let RowParams { session, row } = params(params_value)?;
+                self.rows(session)?;
+                let rows = self.sessions.get_mut(&session).unwrap();
+                let remaining = try_push_row(rows, row)?.len();
+                Ok(json!({ "rows": rows, "remaining": remaining }))
+            },
+            "session.undo" => {
+                let SessionParams { session } = params(params_value)?;
+                self.rows(session)?;
+                let rows = self.sessions.get_mut(&session).unwrap();
+                rows.pop();
+                Ok(json!({ "rows": rows }))
+            },
+            "session.close" => {
+                let SessionParams { session } = params(params_value)?;
+                self.rows(session)?;
+                self.sessions.remove(&session);
+                Ok(Value::Null)
+            },
+            "candidates" => {
+                let SessionParams { session } = params(params_value)?;
+                let words = candidates(self.rows(session)?)?;
+                Ok(json!({ "remaining": words.len(), "candidates": words }))
+            },
+            "rank" => {
+                let RankParams { session, count } = params(params_value)?;
+                let ranking = ranking(self.rows(session)?, count.unwrap_or(DEFAULT_RANK_COUNT))?;
+                Ok(serde_json::to_value(ranking).unwrap())
+            },
+            "explain" => {
+                let ExplainParams { session, word } = params(params_value)?;
+                Ok(serde_json::to_value(explain_word(&word, self.rows(session)?)?).unwrap())
+            },
+            _ => Err(RpcError::new(METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+        }
+    }
+
+    /// Response to the request line, nothing for the notifications.
+    pub fn handle_line(&mut self, line: &str) -> Option<Value> {
+        let value: Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(err) => return Some(error_response(Value::Null, RpcError::new(PARSE_ERROR, err))),
+        };
+        let id = value.get("id").cloned().unwrap_or(Value::Null);
+        let request: Request = match serde_json::from_value(value) {
+            Ok(request) => request,
+            Err(err) => return Some(error_response(id, RpcError::new(INVALID_REQUEST, err))),
+        };
+        if request.jsonrpc != "2.0" {
+            return Some(error_response(id, RpcError::new(INVALID_REQUEST, "Only JSON-RPC 2.0 is supported")));
+        }
+        let result = self.call(&request.method, request.params);
+        let id = request.id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(err) => error_response(id, err),
+        })
+    }
+}
+
+fn error_response(id: Value, err: RpcError) -> Value {
+    let mut error = json!({ "code": err.code, "message": err.message });
+    if let Some(data) = err.data {
+        error["data"] = data;
+    }
+    json!({ "jsonrpc": "2.0", "id": id, "error": error })
+}
+
+/// Reads a request per line until the input ends, writes a response per line.
+pub fn run(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut rpc = Rpc::default();
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = rpc.handle_line(&line) {
+            writeln!(output, "{}", response)?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
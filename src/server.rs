@@ -0,0 +1,252 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+use tinkoff_guess_game_lib::{letters_to_string, Error};
+use tinkoff_guess_game_lib::explain::explain_word;
+use tinkoff_guess_game_lib::game::{Game, Host};
+use tinkoff_guess_game_lib::import::import_game;
+use tinkoff_guess_game_lib::keyboard::keyboard_state;
+use tinkoff_guess_game_lib::rank::DEFAULT_RANK_COUNT;
+use tinkoff_guess_game_lib::solve::{ranking, solve};
+
+/// Bigger bodies are refused before they are read, the largest real one is a pasted game.
+const MAX_BODY_SIZE: usize = 64 * 1024;
+/// The oldest games are forgotten, so the abandoned ones don't pile up.
+const MAX_GAMES: usize = 10_000;
+
+/// Same page as in the desktop app, it talks to this server when there is no Tauri around.
+const INDEX_HTML: &str = include_str!("../ui/index.html");
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RowsRequest {
+    rows: Vec<String>,
+    count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportRequest {
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExplainRequest {
+    word: String,
+    #[serde(default)]
+    rows: Vec<String>,
+}
+
+/// The game is replayed on every request, without `game` a new one is started.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PlayRequest {
+    game: Option<String>,
+    guesses: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PlayResponse {
+    game: String,
+    rows: Vec<String>,
+    attempts: usize,
+    max_attempts: Option<usize>,
+    won: bool,
+    over: bool,
+    /// Shown only when the game is over.
+    answer: Option<&'static str>,
+    share: Option<String>,
+}
+
+/// Seeds of the started games by their ids. The seed picks the answer, so it never leaves the server.
+#[derive(Debug, Default)]
+struct Games {
+    seeds: HashMap<String, u64>,
+    started: VecDeque<String>,
+}
+
+impl Games {
+    fn start(&mut self) -> (String, u64) {
+        if self.started.len() >= MAX_GAMES {
+            if let Some(id) = self.started.pop_front() {
+                self.seeds.remove(&id);
+            }
+        }
+        let id = format!("{:016x}", rand::random::<u64>());
+        let seed = rand::random();
+        self.seeds.insert(id.clone(), seed);
+        self.started.push_back(id.clone());
+        (id, seed)
+    }
+
+    fn seed(&self, id: &str) -> Option<u64> {
+        self.seeds.get(id).copied()
+    }
+}
+
+#[derive(Debug)]
+enum ApiError {
+    BadRequest(String),
+    NotFound(String),
+    TooLarge,
+    Solver(Error),
+}
+
+impl ApiError {
+    fn status(&self) -> u16 {
+        match self {
+            ApiError::NotFound(_) => 404,
+            ApiError::TooLarge => 413,
+            _ => 400,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::NotFound(_) => "not_found",
+            ApiError::TooLarge => "too_large",
+            ApiError::Solver(err) => err.code(),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApiError::BadRequest(err) => write!(f, "Bad request: {}", err),
+            ApiError::NotFound(path) => write!(f, "Not found: {}", path),
+            ApiError::TooLarge => write!(f, "Request body is larger than {} bytes", MAX_BODY_SIZE),
+            ApiError::Solver(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(err: Error) -> ApiError {
+        ApiError::Solver(err)
+    }
+}
+
+fn parse_body<'a, T: Deserialize<'a>>(body: &'a str) -> Result<T, ApiError> {
+    let body = if body.trim().is_empty() { "{}" } else { body };
+    serde_json::from_str(body).map_err(|err| ApiError::BadRequest(err.to_string()))
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+fn play(games: &mut Games, request: PlayRequest) -> Result<PlayResponse, ApiError> {
+    let (id, seed) = match request.game {
+        Some(id) => match games.seed(&id) {
+            Some(seed) => (id, seed),
+            None => return Err(ApiError::NotFound(format!("game {}", id))),
+        },
+        None => games.start(),
+    };
+    let mut game = Game::with_seed(seed);
+    for guess in request.guesses.iter() {
+        game.guess(guess).map_err(Error::from)?;
+    }
+    let over = game.is_over();
+    Ok(PlayResponse {
+        game: id,
+        rows: game.rows().iter().map(|row| letters_to_string(row)).collect(),
+        attempts: game.attempts(),
+        max_attempts: game.max_attempts(),
+        won: game.is_won(),
+        over,
+        answer: game.answer().filter(|_| over),
+        share: over.then(|| game.share_grid()),
+    })
+}
+
+fn route(games: &mut Games, method: &Method, path: &str, body: &str) -> Result<Value, ApiError> {
+    match (method, path) {
+        (Method::Post, "/solve") => {
+            let request: RowsRequest = parse_body(body)?;
+            Ok(to_value(&solve(&request.rows)?))
+        },
+        (Method::Post, "/rank") => {
+            let request: RowsRequest = parse_body(body)?;
+            Ok(to_value(&ranking(&request.rows, request.count.unwrap_or(DEFAULT_RANK_COUNT))?))
+        },
+        (Method::Post, "/explain") => {
+            let request: ExplainRequest = parse_body(body)?;
+            Ok(to_value(&explain_word(&request.word, &request.rows)?))
+        },
+        (Method::Get | Method::Post, "/suggest-openers") => {
+            let mut openers = solve(&[])?.candidates;
+            if let Some(count) = parse_body::<RowsRequest>(body)?.count {
+                openers.truncate(count);
+            }
+            Ok(to_value(&openers))
+        },
+        (Method::Post, "/keyboard") => {
+            let request: RowsRequest = parse_body(body)?;
+            Ok(to_value(&keyboard_state(&request.rows)?))
+        },
+        (Method::Post, "/import") => {
+            let request: ImportRequest = parse_body(body)?;
+            Ok(to_value(&import_game(&request.text)?))
+        },
+        (Method::Post, "/play") => Ok(to_value(&play(games, parse_body(body)?)?)),
+        _ => Err(ApiError::NotFound(format!("{} {}", method, path))),
+    }
+}
+
+fn read_body(request: &mut Request) -> Result<String, ApiError> {
+    if request.body_length().is_some_and(|length| length > MAX_BODY_SIZE) {
+        return Err(ApiError::TooLarge);
+    }
+    // Without the length the body is read up to the limit and a byte more to see it is exceeded.
+    let mut body = Vec::new();
+    request.as_reader()
+        .take(MAX_BODY_SIZE as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|err| ApiError::BadRequest(err.to_string()))?;
+    if body.len() > MAX_BODY_SIZE {
+        return Err(ApiError::TooLarge);
+    }
+    String::from_utf8(body).map_err(|err| ApiError::BadRequest(err.to_string()))
+}
+
+fn handle(mut request: Request, games: &mut Games) {
+    let path = request.url().split('?').next().unwrap_or_default().to_string();
+    if request.method() == &Method::Get && (path == "/" || path == "/index.html") {
+        let response = Response::from_string(INDEX_HTML)
+            .with_header(Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap());
+        if let Err(err) = request.respond(response) {
+            eprintln!("Response is not sent: {}", err);
+        }
+        return;
+    }
+
+    let result = read_body(&mut request).and_then(|body| route(games, request.method(), &path, &body));
+    let (status, value) = match result {
+        Ok(value) => (200, value),
+        Err(err) => (err.status(), json!({ "error": { "code": err.code(), "message": err.to_string() } })),
+    };
+    let response = Response::from_string(value.to_string())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json; charset=utf-8").unwrap());
+    if let Err(err) = request.respond(response) {
+        eprintln!("Response is not sent: {}", err);
+    }
+}
+
+/// Serves the requests one by one until the process is stopped.
+pub fn serve(addr: &str) -> Result<(), String> {
+    let server = Server::http(addr).map_err(|err| err.to_string())?;
+    // The port may be chosen by the system, the tests read it from here.
+    println!("Listening on http://{}", server.server_addr());
+    let mut games = Games::default();
+    for request in server.incoming_requests() {
+        handle(request, &mut games);
+    }
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use std::path::Path;
+use std::process::Command;
+
+fn solve_in_session(data_dir: &Path, row: &str) {
+    let status = Command::new(env!("CARGO_BIN_EXE_main"))
+        .args(["solve", "--session", "game", row])
+        .env("XDG_DATA_HOME", data_dir)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success());
+}
+
+#[test]
+fn test_lost_session_is_recorded_once() {
+    let data_dir = std::env::temp_dir().join(format!("tinkoff-cli-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    for row in ["лента", "судья", "игрок", "шпунт", "вздор", "хлябь", "мышца"] {
+        solve_in_session(&data_dir, row);
+    }
+    let history = std::fs::read_to_string(data_dir.join("tinkoff_guess_game_helper/history.jsonl")).unwrap();
+    assert_eq!(history.lines().count(), 1);
+    std::fs::remove_dir_all(&data_dir).unwrap();
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "ffi")]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// The header generated by build.rs for this build.
+const HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/tinkoff_guess_game.h"));
+
+// A separate target directory, so the nested build does not wait for the lock of this one.
+fn build_staticlib(manifest_dir: &Path) -> PathBuf {
+    let target_dir = manifest_dir.join("target/ffi-test");
+    let status = Command::new(env!("CARGO"))
+        .args(["rustc", "--lib", "--features", "ffi", "--crate-type", "staticlib", "--manifest-path"])
+        .arg(manifest_dir.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    target_dir.join("debug/libtinkoff_guess_game_lib.a")
+}
+
+#[test]
+fn test_header_is_current() {
+    let kept = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("include/tinkoff_guess_game.h")).unwrap();
+    assert!(kept == HEADER, "include/tinkoff_guess_game.h is outdated, rebuild with TGG_REGENERATE_HEADER=1");
+}
+
+#[test]
+fn test_c_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let library = build_staticlib(&manifest_dir);
+    let program = library.with_file_name("ffi_session_test");
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| String::from("cc")))
+        .arg(manifest_dir.join("tests/ffi/session.c"))
+        .arg("-I").arg(env!("OUT_DIR"))
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!output.stdout.is_empty());
+}
@@ -0,0 +1,29 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "tinkoff_guess_game.h"
+
+#define CHECK(cond) do { if (!(cond)) { fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #cond); return 1; } } while (0)
+
+int main(void) {
+    TggSession *session = tgg_session_new();
+    CHECK(session != NULL);
+
+    CHECK(tgg_session_add_row(session, "лента") == TggStatus_Ok);
+    CHECK(tgg_session_add_row(session, "с=удь=я") == TggStatus_Ok);
+    CHECK(tgg_session_add_row(session, "лент") == TggStatus_WrongRow);
+    CHECK(strstr(tgg_session_last_error(session), "лент") != NULL);
+    CHECK(tgg_session_candidate_count(session) == 5);
+
+    char *candidates = tgg_session_candidates(session);
+    CHECK(strcmp(candidates, "гурия\nкурия\nмумия\nрупия\nфурия") == 0);
+    tgg_string_free(candidates);
+
+    char *ranking = tgg_session_rank(session, 1);
+    CHECK(strchr(ranking, '\t') != NULL);
+    printf("%s\n", ranking);
+    tgg_string_free(ranking);
+
+    tgg_session_free(session);
+    return 0;
+}
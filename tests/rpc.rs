@@ -0,0 +1,69 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+use serde_json::{json, Value};
+
+/// Sends the requests one per line and reads the responses the same way.
+fn call_rpc(requests: &[Value]) -> Vec<Value> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_main"))
+        .arg("rpc")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    for request in requests {
+        writeln!(stdin, "{}", request).unwrap();
+    }
+    writeln!(stdin, "not json").unwrap();
+    drop(stdin);
+    let responses = BufReader::new(child.stdout.take().unwrap())
+        .lines()
+        .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+        .collect();
+    assert!(child.wait().unwrap().success());
+    responses
+}
+
+fn request(id: u64, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+#[test]
+fn test_rpc_session() {
+    let responses = call_rpc(&[
+        request(1, "session.new", json!({})),
+        request(2, "session.add_row", json!({ "session": 1, "row": "лента" })),
+        request(3, "session.add_row", json!({ "session": 1, "row": "с=удь=я" })),
+        request(4, "session.add_row", json!({ "session": 1, "row": "лент" })),
+        request(5, "candidates", json!({ "session": 1 })),
+        request(6, "rank", json!({ "session": 1, "count": 2 })),
+        request(7, "explain", json!({ "session": 1, "word": "мумия" })),
+        json!({ "jsonrpc": "2.0", "method": "session.undo", "params": { "session": 1 } }),
+        request(8, "candidates", json!({ "session": 1 })),
+    ]);
+    assert_eq!(responses.len(), 9);
+    assert_eq!(responses[0]["result"]["session"], 1);
+    assert_eq!(responses[2]["result"]["remaining"], 5);
+    assert_eq!(responses[3]["error"]["data"]["code"], "wrong_row");
+    assert_eq!(responses[4]["result"]["candidates"], json!(["гурия", "курия", "мумия", "рупия", "фурия"]));
+    assert_eq!(responses[5]["result"].as_array().unwrap().len(), 2);
+    assert_eq!(responses[6]["result"]["candidate"], true);
+    // The notification has no response, but the row is gone.
+    assert_eq!(responses[7]["id"], 8);
+    assert_eq!(responses[7]["result"]["remaining"], responses[1]["result"]["remaining"]);
+    assert_eq!(responses[8]["error"]["code"], -32700);
+}
+
+#[test]
+fn test_rpc_errors() {
+    let responses = call_rpc(&[
+        request(1, "candidates", json!({ "session": 42 })),
+        request(2, "nothing", json!({})),
+        json!({ "jsonrpc": "1.0", "id": 3, "method": "session.new" }),
+    ]);
+    assert_eq!(responses[0]["error"]["code"], -32602);
+    assert_eq!(responses[1]["error"]["code"], -32601);
+    assert_eq!(responses[2]["error"]["code"], -32600);
+    assert_eq!(responses[2]["id"], 3);
+}
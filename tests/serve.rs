@@ -0,0 +1,106 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+
+use serde_json::{json, Value};
+
+/// The server started on a free port, stopped when dropped.
+struct TestServer {
+    child: Child,
+    url: String,
+}
+
+impl TestServer {
+    fn start() -> TestServer {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_main"))
+            .args(["serve", "--port", "0"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+        let url = line.trim().strip_prefix("Listening on ").unwrap().to_string();
+        TestServer { child, url }
+    }
+
+    fn post(&self, path: &str, body: Value) -> (u16, Value) {
+        let result = ureq::post(&format!("{}{}", self.url, path)).send_json(body);
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => panic!("{}", err),
+        };
+        (response.status(), response.into_json().unwrap())
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn test_serve_ui() {
+    let server = TestServer::start();
+    let response = ureq::get(&server.url).call().unwrap();
+    assert!(response.content_type().starts_with("text/html"));
+    let page = response.into_string().unwrap();
+    assert!(page.contains("httpBackend"));
+}
+
+#[test]
+fn test_serve() {
+    let server = TestServer::start();
+
+    let (status, solution) = server.post("/solve", json!({ "rows": ["лента", "с=удь=я", "?игрок"] }));
+    assert_eq!(status, 200);
+    assert_eq!(solution["remaining"], 1);
+    assert_eq!(solution["candidates"][0]["word"], "мумия");
+
+    let (status, error) = server.post("/solve", json!({ "rows": ["лент"] }));
+    assert_eq!(status, 400);
+    assert_eq!(error["error"]["code"], "wrong_row");
+
+    let (status, ranking) = server.post("/rank", json!({ "rows": ["лента", "с=удь=я"], "count": 3 }));
+    assert_eq!(status, 200);
+    assert_eq!(ranking.as_array().unwrap().len(), 3);
+
+    let (status, explanation) = server.post("/explain", json!({ "word": "мумия", "rows": ["лента", "с=удь=я"] }));
+    assert_eq!(status, 200);
+    assert_eq!(explanation["candidate"], true);
+
+    let (status, openers) = server.post("/suggest-openers", json!({ "count": 2 }));
+    assert_eq!(status, 200);
+    assert_eq!(openers.as_array().unwrap().len(), 2);
+
+    let (status, game) = server.post("/play", json!({}));
+    assert_eq!(status, 200);
+    assert_eq!((game["attempts"].as_u64(), game["over"].as_bool()), (Some(0), Some(false)));
+    assert!(game.get("seed").is_none());
+    let id = game["game"].clone();
+    let (_, game) = server.post("/play", json!({ "game": id, "guesses": ["лента"] }));
+    assert_eq!(game["rows"].as_array().unwrap().len(), 1);
+    let (status, error) = server.post("/play", json!({ "game": id, "guesses": ["ыыыыы"] }));
+    assert_eq!((status, error["error"]["code"].as_str()), (400, Some("unknown_word")));
+    let (status, error) = server.post("/play", json!({ "game": "0", "guesses": [] }));
+    assert_eq!((status, error["error"]["code"].as_str()), (404, Some("not_found")));
+
+    let (status, state) = server.post("/keyboard", json!({ "rows": ["с=удь=я"] }));
+    assert_eq!(status, 200);
+    assert_eq!(state["letters"]["у"], "correct");
+
+    let (status, rows) = server.post("/import", json!({ "text": "лента судья\n⬛⬛⬛⬛⬛\n⬛🟨⬛⬛🟨" }));
+    assert_eq!(status, 200);
+    assert_eq!(rows, json!(["лента", "с=удь=я"]));
+
+    let (status, error) = server.post("/nothing", json!({}));
+    assert_eq!((status, error["error"]["code"].as_str()), (404, Some("not_found")));
+}
+
+#[test]
+fn test_serve_body_limit() {
+    let server = TestServer::start();
+    let (status, error) = server.post("/import", json!({ "text": "лента ".repeat(20_000) }));
+    assert_eq!((status, error["error"]["code"].as_str()), (413, Some("too_large")));
+}